@@ -13,8 +13,19 @@ use stroma::freenet::trust_contract::TrustNetworkState;
 use stroma::matchmaker::cluster_detection::detect_clusters;
 use stroma::matchmaker::dvr::calculate_dvr;
 use stroma::matchmaker::graph_analysis::TrustGraph;
+use stroma::matchmaker::parallel::with_thread_pool;
+use stroma::freenet::routing_table::RoutingTable;
 use stroma::matchmaker::strategic_intro::suggest_introductions;
 
+/// Helper to create a unique test member hash from a larger index space than
+/// `test_member` (which aliases every 256 ids), for benchmarks that need
+/// hundreds or thousands of distinct members.
+fn indexed_member(index: usize) -> MemberHash {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&(index as u64).to_be_bytes());
+    MemberHash::from_bytes(&bytes)
+}
+
 /// Helper to create test member hash
 fn test_member(id: u8) -> MemberHash {
     MemberHash::from_bytes(&[id; 32])
@@ -201,6 +212,81 @@ fn benchmark_blind_matchmaker(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark the Kademlia-style XOR routing table
+fn benchmark_routing_table(c: &mut Criterion) {
+    let mut group = c.benchmark_group("routing_table");
+
+    for size in [500, 1000].iter() {
+        let local_id = indexed_member(0);
+
+        group.bench_with_input(
+            BenchmarkId::new("insert", size),
+            size,
+            |b, &size| {
+                b.iter(|| {
+                    let mut table = RoutingTable::new(local_id);
+                    for i in 1..=size {
+                        table.insert(black_box(indexed_member(i)));
+                    }
+                    table
+                });
+            },
+        );
+
+        let mut table = RoutingTable::new(local_id);
+        for i in 1..=*size {
+            table.insert(indexed_member(i));
+        }
+        let target = indexed_member(size / 2);
+
+        group.bench_with_input(
+            BenchmarkId::new("closest_n_20", size),
+            size,
+            |b, _| {
+                b.iter(|| table.closest_n(black_box(&target), 20));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// Benchmark Bloom-filter set reconciliation
+fn benchmark_reconciliation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reconciliation");
+    group.sample_size(20);
+
+    let network = create_test_network(1000, 6);
+
+    group.bench_function("build_filters_1000_members", |b| {
+        b.iter(|| network.build_reconciliation_filters(black_box(50)));
+    });
+
+    let filters = network.build_reconciliation_filters(50);
+    group.bench_function("respond_to_filters_1000_members", |b| {
+        b.iter(|| network.respond_to_filters(black_box(&filters)));
+    });
+
+    group.finish();
+}
+
+/// Benchmark BFS hop-count distance calculation
+fn benchmark_calculate_distances(c: &mut Criterion) {
+    let mut group = c.benchmark_group("calculate_distances");
+
+    for size in [20, 100, 500, 1000].iter() {
+        let network = create_test_network(*size, 4);
+        let graph = TrustGraph::from_state(&network);
+        let source = test_member(0);
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, _| {
+            b.iter(|| graph.calculate_distances(black_box(source)));
+        });
+    }
+
+    group.finish();
+}
+
 /// Benchmark TrustGraph construction
 fn benchmark_graph_construction(c: &mut Criterion) {
     let mut group = c.benchmark_group("graph_construction");
@@ -236,13 +322,66 @@ fn benchmark_combined_analysis(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark the Rayon-parallelized analysis functions at a few fixed
+/// thread-pool sizes, to see how the parallelization in `calculate_dvr`,
+/// `detect_clusters`, and `suggest_introductions` scales with thread count.
+fn benchmark_thread_pool_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("thread_pool_scaling");
+    group.sample_size(20);
+
+    let xlarge_network = create_test_network(1000, 6);
+    let xlarge_clusters = create_clustered_network(10, 50);
+    let xlarge_graph = TrustGraph::from_state(&xlarge_clusters);
+
+    for threads in [1, 2, 4, 8].iter() {
+        group.bench_with_input(
+            BenchmarkId::new("dvr_xlarge_1000_members", threads),
+            threads,
+            |b, &threads| {
+                b.iter(|| {
+                    with_thread_pool(threads, || calculate_dvr(black_box(&xlarge_network)))
+                        .unwrap()
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("cluster_detection_xlarge_500_members", threads),
+            threads,
+            |b, &threads| {
+                b.iter(|| {
+                    with_thread_pool(threads, || detect_clusters(black_box(&xlarge_clusters)))
+                        .unwrap()
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("blind_matchmaker_xlarge_500_members", threads),
+            threads,
+            |b, &threads| {
+                b.iter(|| {
+                    with_thread_pool(threads, || suggest_introductions(black_box(&xlarge_graph)))
+                        .unwrap()
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     benchmark_dvr_calculation,
     benchmark_cluster_detection,
     benchmark_blind_matchmaker,
+    benchmark_routing_table,
+    benchmark_reconciliation,
+    benchmark_calculate_distances,
     benchmark_graph_construction,
-    benchmark_combined_analysis
+    benchmark_combined_analysis,
+    benchmark_thread_pool_scaling
 );
 
 criterion_main!(benches);
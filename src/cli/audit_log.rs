@@ -0,0 +1,218 @@
+//! Append-only audit trail for destructive CLI operations
+//!
+//! Every destructive action (`delete_account`, local clear, secondary
+//! unlink, remote device unlink) is written twice to a log file next to
+//! the store: once with
+//! `Outcome::Started` *before* the mutation runs, and again with
+//! `Outcome::Succeeded`/`Outcome::Failed` afterward. Writing the intent
+//! entry before the mutation - analogous to logging a delete event prior to
+//! committing it - means a crash mid-deletion still leaves a forensic record
+//! of what was attempted.
+//!
+//! Pair with `--output json` on `stroma unregister`, which emits the same
+//! record to stdout as the log entries, instead of the usual prose banners,
+//! so the command can be driven from automation.
+
+use super::clock::current_timestamp;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Name of the audit log file, written next to the store directory.
+const AUDIT_LOG_FILENAME: &str = "audit.log.jsonl";
+
+/// Kind of destructive operation being recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    /// Permanent account deletion from Signal's servers (`--delete-account`).
+    DeleteAccount,
+    /// Local-only data clear (primary device, no `--delete-account`).
+    LocalClear,
+    /// Local-only data clear on a secondary device.
+    SecondaryUnlink,
+    /// Remote unlink of a device from Signal's servers (`stroma devices
+    /// unlink`, or the automatic cleanup step during `unregister`).
+    DeviceUnlink,
+}
+
+/// Outcome of a recorded operation, in the order it's observed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    /// Intent recorded before the mutation runs.
+    Started,
+    /// The operation completed successfully.
+    Succeeded,
+    /// The operation failed; `detail` carries the error.
+    Failed,
+}
+
+/// A single audit log entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    /// Unix timestamp (seconds since epoch) this entry was written.
+    pub timestamp: u64,
+    /// Path of the store the operation acted on.
+    pub store_path: PathBuf,
+    /// Detected registration type ("primary" or "secondary").
+    pub registration_type: String,
+    /// Masked account identifier (derived from the store path, never raw PII).
+    pub masked_account_id: String,
+    /// Which destructive operation this entry describes.
+    pub operation: OperationKind,
+    /// Whether `--yes` was used to skip interactive confirmation.
+    pub used_yes_flag: bool,
+    /// Where in the operation's lifecycle this entry was written.
+    pub outcome: Outcome,
+    /// Error detail, set only when `outcome` is `Failed`.
+    pub detail: Option<String>,
+}
+
+/// Path of the audit log, next to the store directory (not inside it, so a
+/// trashed or deleted store doesn't take its own audit trail with it).
+pub fn audit_log_path(store_path: &Path) -> PathBuf {
+    store_path
+        .parent()
+        .unwrap_or(store_path)
+        .join(AUDIT_LOG_FILENAME)
+}
+
+/// Derive a masked account identifier from the store path.
+///
+/// Each account has a dedicated store, so the store path is a stable proxy
+/// for account identity. Hashing it keeps the phone number/UUID out of the
+/// audit trail, and avoids depending on a network round-trip (`whoami`) in
+/// the middle of a destructive operation.
+pub fn mask_account_id(store_path: &Path) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(store_path.to_string_lossy().as_bytes());
+    let digest = hasher.finalize();
+    format!("acct:{}", hex::encode(&digest[..4]))
+}
+
+/// Append one entry to the audit log next to `store_path`.
+pub fn append_entry(
+    store_path: &Path,
+    entry: &AuditLogEntry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let path = audit_log_path(store_path);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open audit log {}: {}", path.display(), e))?;
+
+    let json = serde_json::to_string(entry)
+        .map_err(|e| format!("Failed to serialize audit log entry: {}", e))?;
+    writeln!(file, "{}", json).map_err(|e| format!("Failed to write audit log entry: {}", e))?;
+
+    Ok(())
+}
+
+/// Build and append a `Started` entry, returning it so the caller can reuse
+/// its fields when recording the outcome.
+pub fn start(
+    store_path: &Path,
+    registration_type: &str,
+    operation: OperationKind,
+    used_yes_flag: bool,
+) -> Result<AuditLogEntry, Box<dyn std::error::Error>> {
+    let entry = AuditLogEntry {
+        timestamp: current_timestamp(),
+        store_path: store_path.to_path_buf(),
+        registration_type: registration_type.to_string(),
+        masked_account_id: mask_account_id(store_path),
+        operation,
+        used_yes_flag,
+        outcome: Outcome::Started,
+        detail: None,
+    };
+    append_entry(store_path, &entry)?;
+    Ok(entry)
+}
+
+/// Append a follow-up entry recording the outcome of a previously-started operation.
+pub fn finish(
+    started: &AuditLogEntry,
+    result: &Result<(), String>,
+) -> Result<AuditLogEntry, Box<dyn std::error::Error>> {
+    let entry = AuditLogEntry {
+        timestamp: current_timestamp(),
+        outcome: match result {
+            Ok(()) => Outcome::Succeeded,
+            Err(_) => Outcome::Failed,
+        },
+        detail: result.clone().err(),
+        ..started.clone()
+    };
+    append_entry(&started.store_path, &entry)?;
+    Ok(entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_mask_account_id_is_deterministic_and_masked() {
+        let path = Path::new("/data/stroma/signal-store");
+        let masked = mask_account_id(path);
+
+        assert_eq!(masked, mask_account_id(path));
+        assert!(masked.starts_with("acct:"));
+        assert!(!masked.contains("signal-store"));
+    }
+
+    #[test]
+    fn test_mask_account_id_differs_per_store() {
+        let a = mask_account_id(Path::new("/data/stroma/store-a"));
+        let b = mask_account_id(Path::new("/data/stroma/store-b"));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_start_then_finish_appends_two_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        let store_path = temp_dir.path().join("signal-store");
+        std::fs::create_dir(&store_path).unwrap();
+
+        let started = start(&store_path, "primary", OperationKind::LocalClear, false).unwrap();
+        assert_eq!(started.outcome, Outcome::Started);
+
+        finish(&started, &Ok(())).unwrap();
+
+        let log_contents = std::fs::read_to_string(audit_log_path(&store_path)).unwrap();
+        let lines: Vec<&str> = log_contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let first: AuditLogEntry = serde_json::from_str(lines[0]).unwrap();
+        let second: AuditLogEntry = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(first.outcome, Outcome::Started);
+        assert_eq!(second.outcome, Outcome::Succeeded);
+    }
+
+    #[test]
+    fn test_finish_records_failure_detail() {
+        let temp_dir = TempDir::new().unwrap();
+        let store_path = temp_dir.path().join("signal-store");
+        std::fs::create_dir(&store_path).unwrap();
+
+        let started =
+            start(&store_path, "secondary", OperationKind::SecondaryUnlink, true).unwrap();
+        let finished = finish(&started, &Err("boom".to_string())).unwrap();
+
+        assert_eq!(finished.outcome, Outcome::Failed);
+        assert_eq!(finished.detail.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn test_audit_log_path_is_adjacent_to_store() {
+        let store_path = Path::new("/data/stroma/signal-store");
+        let log_path = audit_log_path(store_path);
+        assert_eq!(log_path, Path::new("/data/stroma/audit.log.jsonl"));
+    }
+}
@@ -0,0 +1,19 @@
+//! Shared wall-clock timestamp helper
+//!
+//! Factored out of `audit_log` so every command that stamps a record with
+//! "now" (audit entries, trash metadata, retention checks) agrees on the
+//! same epoch-seconds representation instead of each reimplementing the
+//! `SystemTime`/`UNIX_EPOCH` dance.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Current time as Unix epoch seconds.
+///
+/// Falls back to `0` if the system clock is somehow set before the epoch,
+/// which in practice never happens on a real host.
+pub fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,246 @@
+//! Shared interactive confirmation prompt
+//!
+//! Factored out of `unregister` so every destructive command (device
+//! unlink, trash purge, unregister/delete-account) requires the operator to
+//! type back an exact confirmation phrase the same way, instead of each
+//! command duplicating its own prompt-and-match loop.
+
+use std::io::{self, Write};
+
+/// Check whether raw user input matches the expected confirmation phrase.
+///
+/// Handles whitespace trimming and exact string matching.
+///
+/// # Arguments
+/// * `input` - Raw user input (may include newlines/whitespace)
+/// * `expected` - The exact string expected for confirmation
+///
+/// # Returns
+/// * `true` if input matches expected after trimming
+/// * `false` otherwise
+pub fn parse_confirmation(input: &str, expected: &str) -> bool {
+    input.trim() == expected
+}
+
+/// Prompt user for confirmation with a specific expected input
+pub fn confirm_action(prompt: &str, expected: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    print!("{}", prompt);
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(parse_confirmation(&input, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_confirmation_exact_match() {
+        assert!(parse_confirmation("DELETE", "DELETE"));
+        assert!(parse_confirmation("UNREGISTER", "UNREGISTER"));
+        assert!(parse_confirmation("UNLINK", "UNLINK"));
+    }
+
+    #[test]
+    fn test_parse_confirmation_with_whitespace() {
+        // Should handle trailing newline from stdin
+        assert!(parse_confirmation("DELETE\n", "DELETE"));
+        assert!(parse_confirmation("DELETE\r\n", "DELETE"));
+        assert!(parse_confirmation("  DELETE  ", "DELETE"));
+        assert!(parse_confirmation("\tDELETE\t", "DELETE"));
+    }
+
+    #[test]
+    fn test_parse_confirmation_wrong_input() {
+        assert!(!parse_confirmation("delete", "DELETE")); // case sensitive
+        assert!(!parse_confirmation("DELET", "DELETE")); // partial
+        assert!(!parse_confirmation("DELETE!", "DELETE")); // extra char
+        assert!(!parse_confirmation("", "DELETE")); // empty
+        assert!(!parse_confirmation("no", "DELETE")); // wrong word
+    }
+
+    #[test]
+    fn test_parse_confirmation_empty_expected() {
+        // Edge case: empty expected string
+        assert!(parse_confirmation("", ""));
+        assert!(parse_confirmation("  ", ""));
+        assert!(!parse_confirmation("x", ""));
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::test_runner::{Config as ProptestConfig, RngAlgorithm, TestRng, TestRunner};
+
+    const PROPTEST_SEED: &[u8; 32] = b"stroma-confirm-proptest------32b";
+
+    /// Property: parse_confirmation never panics
+    #[test]
+    fn prop_parse_confirmation_never_panics() {
+        let config = ProptestConfig {
+            rng_algorithm: RngAlgorithm::ChaCha,
+            ..Default::default()
+        };
+        let mut runner = TestRunner::new_with_rng(
+            config,
+            TestRng::from_seed(RngAlgorithm::ChaCha, PROPTEST_SEED),
+        );
+
+        let strategy = (".*", ".*");
+
+        runner
+            .run(&strategy, |(input, expected)| {
+                // Should handle any string inputs without panicking
+                let _ = parse_confirmation(&input, &expected);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Property: Whitespace normalization is symmetric
+    #[test]
+    fn prop_whitespace_normalization() {
+        let config = ProptestConfig {
+            rng_algorithm: RngAlgorithm::ChaCha,
+            ..Default::default()
+        };
+        let mut runner = TestRunner::new_with_rng(
+            config,
+            TestRng::from_seed(RngAlgorithm::ChaCha, PROPTEST_SEED),
+        );
+
+        let strategy = ("[A-Z]+", " *", " *");
+
+        runner
+            .run(&strategy, |(s, prefix_ws, suffix_ws)| {
+                let input = format!("{}{}{}", prefix_ws, s, suffix_ws);
+                let result = parse_confirmation(&input, &s);
+
+                prop_assert!(result, "Whitespace should be trimmed: {:?}", input);
+
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Property: Case sensitivity is preserved
+    #[test]
+    fn prop_case_sensitive() {
+        let config = ProptestConfig {
+            rng_algorithm: RngAlgorithm::ChaCha,
+            ..Default::default()
+        };
+        let mut runner = TestRunner::new_with_rng(
+            config,
+            TestRng::from_seed(RngAlgorithm::ChaCha, PROPTEST_SEED),
+        );
+
+        let strategy = "[a-z]+";
+
+        runner
+            .run(&strategy, |s| {
+                let uppercase = s.to_uppercase();
+                let result = parse_confirmation(&s, &uppercase);
+
+                prop_assert!(!result, "Confirmation must be case-sensitive");
+
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Property: Exact match always succeeds (after trim)
+    #[test]
+    fn prop_exact_match_succeeds() {
+        let config = ProptestConfig {
+            rng_algorithm: RngAlgorithm::ChaCha,
+            ..Default::default()
+        };
+        let mut runner = TestRunner::new_with_rng(
+            config,
+            TestRng::from_seed(RngAlgorithm::ChaCha, PROPTEST_SEED),
+        );
+
+        let strategy = "[A-Za-z0-9]+";
+
+        runner
+            .run(&strategy, |s| {
+                let result = parse_confirmation(&s, &s);
+
+                prop_assert!(result, "Exact match should always succeed");
+
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Property: Empty strings match only empty expected
+    #[test]
+    fn prop_empty_string_behavior() {
+        let config = ProptestConfig {
+            rng_algorithm: RngAlgorithm::ChaCha,
+            cases: 50,
+            ..Default::default()
+        };
+        let mut runner = TestRunner::new_with_rng(
+            config,
+            TestRng::from_seed(RngAlgorithm::ChaCha, PROPTEST_SEED),
+        );
+
+        let strategy = " *";
+
+        runner
+            .run(&strategy, |whitespace| {
+                // Empty input with empty expected should match
+                let result_empty = parse_confirmation(&whitespace, "");
+                prop_assert!(
+                    result_empty,
+                    "Whitespace-only input should match empty expected"
+                );
+
+                // Empty input with non-empty expected should not match
+                let result_nonempty = parse_confirmation(&whitespace, "DELETE");
+                prop_assert!(
+                    !result_nonempty,
+                    "Whitespace-only input should not match non-empty expected"
+                );
+
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    /// Property: Substring does not match
+    #[test]
+    fn prop_substring_no_match() {
+        let config = ProptestConfig {
+            rng_algorithm: RngAlgorithm::ChaCha,
+            cases: 100,
+            ..Default::default()
+        };
+        let mut runner = TestRunner::new_with_rng(
+            config,
+            TestRng::from_seed(RngAlgorithm::ChaCha, PROPTEST_SEED),
+        );
+
+        let strategy = "[A-Z]{5,10}";
+
+        runner
+            .run(&strategy, |s| {
+                if s.len() > 1 {
+                    let substring = &s[..s.len() - 1];
+                    let result = parse_confirmation(substring, &s);
+
+                    prop_assert!(!result, "Substring should not match full string");
+                }
+
+                Ok(())
+            })
+            .unwrap();
+    }
+}
@@ -0,0 +1,71 @@
+use super::audit_log::{self, OperationKind};
+use super::confirm::confirm_action;
+use super::output::OutputFormat;
+use super::store_access::{open_registered_store, resolve_store_path};
+use presage::manager::RegistrationType;
+use std::path::PathBuf;
+use stroma::signal::devices::{list_linked_devices, unlink_device};
+
+/// List devices linked to the Signal account
+pub async fn execute_list(
+    store_path: Option<String>,
+    passphrase_file: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let store_path = resolve_store_path(store_path);
+    let (_store, manager) =
+        open_registered_store(&store_path, passphrase_file, OutputFormat::Text).await?;
+
+    let devices = list_linked_devices(&manager).await?;
+    print_devices(&devices);
+    Ok(())
+}
+
+/// Remotely unlink a device by ID from the Signal account
+pub async fn execute_unlink(
+    id: u32,
+    store_path: Option<String>,
+    passphrase_file: Option<String>,
+    yes: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let store_path = resolve_store_path(store_path);
+    let (_store, manager) =
+        open_registered_store(&store_path, passphrase_file, OutputFormat::Text).await?;
+
+    let registration_type = match manager.registration_type() {
+        RegistrationType::Primary => "primary",
+        RegistrationType::Secondary => "secondary",
+    };
+
+    println!("⚠️  About to remotely unlink device #{} from Signal's servers.", id);
+    if !yes && !confirm_action("Type 'UNLINK' to confirm: ", "UNLINK")? {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let store_path_buf = PathBuf::from(&store_path);
+    let started = audit_log::start(
+        &store_path_buf,
+        registration_type,
+        OperationKind::DeviceUnlink,
+        yes,
+    )?;
+
+    let outcome = unlink_device(&manager, id).await.map_err(|e| e.to_string());
+    audit_log::finish(&started, &outcome)?;
+    outcome?;
+
+    println!("✅ Device #{} unlinked.", id);
+    Ok(())
+}
+
+fn print_devices(devices: &[stroma::signal::devices::LinkedDevice]) {
+    if devices.is_empty() {
+        println!("No linked devices found.");
+        return;
+    }
+
+    println!("📱 Linked devices:");
+    for device in devices {
+        println!("  {}", device);
+    }
+}
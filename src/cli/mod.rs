@@ -1,12 +1,21 @@
 use clap::{Parser, Subcommand};
 
+pub mod audit_log;
 pub mod backup_store;
+pub mod clock;
 pub mod config;
+pub mod confirm;
+pub mod devices;
 pub mod link_device;
+pub mod output;
 pub mod passphrase;
+pub mod purge_trash;
 pub mod register;
+pub mod restore;
 pub mod run;
 pub mod status;
+pub mod store_access;
+pub mod trash;
 pub mod unregister;
 pub mod verify;
 pub mod version;
@@ -131,6 +140,70 @@ pub enum Commands {
         /// Skip confirmation prompt
         #[arg(long, short)]
         yes: bool,
+
+        /// Output format: "text" (default, human-readable) or "json" (machine-readable)
+        #[arg(long, default_value = "text")]
+        output: String,
+    },
+
+    /// Restore a trashed store, or list what's in the trash
+    Restore {
+        /// Name of the trashed store to restore (see `stroma restore` with no args to list)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Restore to a different path than the original
+        #[arg(long)]
+        to: Option<String>,
+    },
+
+    /// Permanently remove trashed stores older than the retention window
+    PurgeTrash {
+        /// Retention window in days; entries older than this are purged
+        #[arg(long, default_value_t = 14)]
+        retention_days: u64,
+
+        /// Skip confirmation prompt
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// Manage devices linked to the Signal account
+    Devices {
+        #[command(subcommand)]
+        action: DevicesAction,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DevicesAction {
+    /// List devices currently linked to the account
+    List {
+        /// Path to Signal protocol store (optional, uses default if not specified)
+        #[arg(long)]
+        store_path: Option<String>,
+
+        /// Path to file containing passphrase (container-native)
+        #[arg(long)]
+        passphrase_file: Option<String>,
+    },
+
+    /// Remotely unlink a device by ID from Signal's servers
+    Unlink {
+        /// Signal device ID to unlink (see `stroma devices list`)
+        id: u32,
+
+        /// Path to Signal protocol store (optional, uses default if not specified)
+        #[arg(long)]
+        store_path: Option<String>,
+
+        /// Path to file containing passphrase (container-native)
+        #[arg(long)]
+        passphrase_file: Option<String>,
+
+        /// Skip confirmation prompt
+        #[arg(long, short)]
+        yes: bool,
     },
 }
 
@@ -183,7 +256,25 @@ pub async fn execute(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
             passphrase_file,
             delete_account,
             yes,
-        } => unregister::execute(store_path, passphrase_file, delete_account, yes).await,
+            output,
+        } => unregister::execute(store_path, passphrase_file, delete_account, yes, output).await,
+        Commands::Restore { name, to } => restore::execute(name, to).await,
+        Commands::PurgeTrash {
+            retention_days,
+            yes,
+        } => purge_trash::execute(retention_days, yes).await,
+        Commands::Devices { action } => match action {
+            DevicesAction::List {
+                store_path,
+                passphrase_file,
+            } => devices::execute_list(store_path, passphrase_file).await,
+            DevicesAction::Unlink {
+                id,
+                store_path,
+                passphrase_file,
+                yes,
+            } => devices::execute_unlink(id, store_path, passphrase_file, yes).await,
+        },
     }
 }
 
@@ -389,11 +480,13 @@ mod tests {
                 passphrase_file,
                 delete_account,
                 yes,
+                output,
             } => {
                 assert_eq!(store_path, None);
                 assert_eq!(passphrase_file, None);
                 assert!(!delete_account);
                 assert!(!yes);
+                assert_eq!(output, "text");
             }
             _ => panic!("Expected Unregister command"),
         }
@@ -409,11 +502,13 @@ mod tests {
                 passphrase_file,
                 delete_account,
                 yes,
+                output,
             } => {
                 assert_eq!(store_path, None);
                 assert_eq!(passphrase_file, None);
                 assert!(delete_account);
                 assert!(yes);
+                assert_eq!(output, "text");
             }
             _ => panic!("Expected Unregister command"),
         }
@@ -436,13 +531,188 @@ mod tests {
                 passphrase_file,
                 delete_account,
                 yes,
+                output,
             } => {
                 assert_eq!(store_path, Some("/tmp/my-store".to_string()));
                 assert_eq!(passphrase_file, Some("/tmp/key".to_string()));
                 assert!(!delete_account);
                 assert!(!yes);
+                assert_eq!(output, "text");
+            }
+            _ => panic!("Expected Unregister command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_unregister_with_json_output() {
+        let cli = Cli::parse_from(["stroma", "unregister", "--output", "json"]);
+
+        match cli.command {
+            Commands::Unregister { output, .. } => {
+                assert_eq!(output, "json");
             }
             _ => panic!("Expected Unregister command"),
         }
     }
+
+    #[test]
+    fn test_cli_parse_restore() {
+        let cli = Cli::parse_from(["stroma", "restore"]);
+
+        match cli.command {
+            Commands::Restore { name, to } => {
+                assert_eq!(name, None);
+                assert_eq!(to, None);
+            }
+            _ => panic!("Expected Restore command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_restore_with_name_and_to() {
+        let cli = Cli::parse_from([
+            "stroma",
+            "restore",
+            "--name",
+            "signal-store-1712345678",
+            "--to",
+            "/tmp/restored-store",
+        ]);
+
+        match cli.command {
+            Commands::Restore { name, to } => {
+                assert_eq!(name, Some("signal-store-1712345678".to_string()));
+                assert_eq!(to, Some("/tmp/restored-store".to_string()));
+            }
+            _ => panic!("Expected Restore command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_purge_trash() {
+        let cli = Cli::parse_from(["stroma", "purge-trash"]);
+
+        match cli.command {
+            Commands::PurgeTrash { retention_days, yes } => {
+                assert_eq!(retention_days, 14); // default
+                assert!(!yes);
+            }
+            _ => panic!("Expected PurgeTrash command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_purge_trash_with_options() {
+        let cli = Cli::parse_from(["stroma", "purge-trash", "--retention-days", "30", "--yes"]);
+
+        match cli.command {
+            Commands::PurgeTrash { retention_days, yes } => {
+                assert_eq!(retention_days, 30);
+                assert!(yes);
+            }
+            _ => panic!("Expected PurgeTrash command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_devices_list() {
+        let cli = Cli::parse_from(["stroma", "devices", "list"]);
+
+        match cli.command {
+            Commands::Devices { action } => match action {
+                DevicesAction::List {
+                    store_path,
+                    passphrase_file,
+                } => {
+                    assert_eq!(store_path, None);
+                    assert_eq!(passphrase_file, None);
+                }
+                _ => panic!("Expected DevicesAction::List"),
+            },
+            _ => panic!("Expected Devices command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_devices_list_with_store_path() {
+        let cli = Cli::parse_from([
+            "stroma",
+            "devices",
+            "list",
+            "--store-path",
+            "/tmp/my-store",
+            "--passphrase-file",
+            "/tmp/key",
+        ]);
+
+        match cli.command {
+            Commands::Devices { action } => match action {
+                DevicesAction::List {
+                    store_path,
+                    passphrase_file,
+                } => {
+                    assert_eq!(store_path, Some("/tmp/my-store".to_string()));
+                    assert_eq!(passphrase_file, Some("/tmp/key".to_string()));
+                }
+                _ => panic!("Expected DevicesAction::List"),
+            },
+            _ => panic!("Expected Devices command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_devices_unlink() {
+        let cli = Cli::parse_from(["stroma", "devices", "unlink", "3"]);
+
+        match cli.command {
+            Commands::Devices { action } => match action {
+                DevicesAction::Unlink {
+                    id,
+                    store_path,
+                    passphrase_file,
+                    yes,
+                } => {
+                    assert_eq!(id, 3);
+                    assert_eq!(store_path, None);
+                    assert_eq!(passphrase_file, None);
+                    assert!(!yes);
+                }
+                _ => panic!("Expected DevicesAction::Unlink"),
+            },
+            _ => panic!("Expected Devices command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_devices_unlink_with_options() {
+        let cli = Cli::parse_from([
+            "stroma",
+            "devices",
+            "unlink",
+            "3",
+            "--store-path",
+            "/tmp/my-store",
+            "--passphrase-file",
+            "/tmp/key",
+            "--yes",
+        ]);
+
+        match cli.command {
+            Commands::Devices { action } => match action {
+                DevicesAction::Unlink {
+                    id,
+                    store_path,
+                    passphrase_file,
+                    yes,
+                } => {
+                    assert_eq!(id, 3);
+                    assert_eq!(store_path, Some("/tmp/my-store".to_string()));
+                    assert_eq!(passphrase_file, Some("/tmp/key".to_string()));
+                    assert!(yes);
+                }
+                _ => panic!("Expected DevicesAction::Unlink"),
+            },
+            _ => panic!("Expected Devices command"),
+        }
+    }
 }
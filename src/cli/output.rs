@@ -0,0 +1,90 @@
+//! Output mode for commands that support machine-readable automation
+//!
+//! Mirrors the `servers` string-based argument convention used elsewhere in
+//! this CLI (see `link_device::parse_server_environment`) rather than a
+//! `clap::ValueEnum`, so invalid values get the same consistent error style.
+
+/// How a command reports its result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable prose banners (default).
+    Text,
+    /// A single machine-readable JSON record to stdout, for automation.
+    Json,
+}
+
+impl OutputFormat {
+    /// Whether prose banners should be suppressed in favor of JSON.
+    pub fn is_json(self) -> bool {
+        matches!(self, OutputFormat::Json)
+    }
+}
+
+/// Parse the `--output` flag value.
+///
+/// # Arguments
+/// * `output` - Output format string ("text" or "json")
+///
+/// # Returns
+/// * `Ok(OutputFormat)` - Parsed output format
+/// * `Err(String)` - Error message for invalid input
+pub fn parse_output_format(output: &str) -> Result<OutputFormat, String> {
+    match output.to_lowercase().as_str() {
+        "text" => Ok(OutputFormat::Text),
+        "json" => Ok(OutputFormat::Json),
+        _ => Err(format!(
+            "Invalid output format: {}. Use 'text' or 'json'",
+            output
+        )),
+    }
+}
+
+/// Print a line unless the output format is JSON.
+///
+/// Prose banners are suppressed in machine-readable mode so stdout stays a
+/// single parseable record.
+macro_rules! say {
+    ($output:expr) => {
+        if !$output.is_json() {
+            println!();
+        }
+    };
+    ($output:expr, $($arg:tt)*) => {
+        if !$output.is_json() {
+            println!($($arg)*);
+        }
+    };
+}
+pub(crate) use say;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_output_format_text() {
+        for value in ["text", "Text", "TEXT"] {
+            assert_eq!(parse_output_format(value), Ok(OutputFormat::Text));
+        }
+    }
+
+    #[test]
+    fn test_parse_output_format_json() {
+        for value in ["json", "Json", "JSON"] {
+            assert_eq!(parse_output_format(value), Ok(OutputFormat::Json));
+        }
+    }
+
+    #[test]
+    fn test_parse_output_format_invalid() {
+        let result = parse_output_format("xml");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Invalid output format"));
+    }
+
+    #[test]
+    fn test_is_json() {
+        assert!(!OutputFormat::Text.is_json());
+        assert!(OutputFormat::Json.is_json());
+    }
+}
@@ -0,0 +1,42 @@
+use super::clock::current_timestamp;
+use super::confirm::confirm_action;
+use super::trash;
+
+/// Permanently remove trashed stores older than the retention window
+///
+/// This is the explicit counterpart to the lazy purge sweep that already
+/// runs at the start of every `stroma unregister`. Useful for operators who
+/// want to reclaim disk space without waiting for their next unregister.
+pub async fn execute(retention_days: u64, yes: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let retention_secs = retention_days * 24 * 60 * 60;
+    let trash_dir = trash::default_trash_dir();
+
+    let candidates: Vec<_> = trash::list_trash_entries(&trash_dir)
+        .into_iter()
+        .filter(|e| e.is_expired(current_timestamp(), retention_secs))
+        .collect();
+
+    if candidates.is_empty() {
+        println!("🗑️  No trashed stores older than {} day(s).", retention_days);
+        return Ok(());
+    }
+
+    println!(
+        "⚠️  {} trashed store(s) are older than {} day(s) and will be PERMANENTLY removed:",
+        candidates.len(),
+        retention_days
+    );
+    for entry in &candidates {
+        println!("  • {}", entry.trash_name);
+    }
+    println!();
+
+    if !yes && !confirm_action("Type 'PURGE' to confirm: ", "PURGE")? {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let purged = trash::purge_expired(&trash_dir, retention_secs)?;
+    println!("✅ Purged {} trashed store(s).", purged.len());
+    Ok(())
+}
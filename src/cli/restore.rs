@@ -0,0 +1,44 @@
+use super::clock::current_timestamp;
+use super::trash::{self, TrashEntry};
+
+/// Restore a trashed store back into place
+///
+/// Without `--name`, lists the stores currently sitting in the trash area
+/// (most recently deleted first) so the operator can pick one. With
+/// `--name`, moves that store back to its original location, or to `--to`
+/// if given.
+pub async fn execute(name: Option<String>, to: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+    let trash_dir = trash::default_trash_dir();
+
+    let Some(name) = name else {
+        let entries = trash::list_trash_entries(&trash_dir);
+        print_trash_entries(&entries);
+        return Ok(());
+    };
+
+    println!("♻️  Restoring store '{}'...", name);
+    let destination = trash::restore_store(&trash_dir, &name, to.as_deref())?;
+
+    println!("✅ Store restored to: {}", destination.display());
+    Ok(())
+}
+
+fn print_trash_entries(entries: &[TrashEntry]) {
+    if entries.is_empty() {
+        println!("🗑️  Trash is empty.");
+        return;
+    }
+
+    println!("🗑️  Trashed stores (most recent first):");
+    println!();
+    for entry in entries {
+        println!(
+            "  {}  (from {}, deleted {}s ago)",
+            entry.trash_name,
+            entry.original_path.display(),
+            entry.age_secs(current_timestamp())
+        );
+    }
+    println!();
+    println!("Restore one with: stroma restore --name <name>");
+}
@@ -197,6 +197,7 @@ pub async fn execute(
         identity_masking_key: *keyring.identity_masking_key(),
         voter_pepper: *keyring.voter_pepper(),
         contract_hash: None, // Freenet not yet available
+        ..Default::default()
     };
 
     // Create MockFreenetClient (real Freenet not yet integrated)
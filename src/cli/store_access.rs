@@ -0,0 +1,109 @@
+//! Shared store-open and passphrase-resolution logic
+//!
+//! Factored out of `unregister` so other destructive/management commands
+//! (`stroma devices list`/`unlink`) resolve the store path, passphrase, and
+//! registered `Manager` the exact same way, instead of duplicating the
+//! priority chain: `--passphrase-file` > env var > default passphrase file
+//! adjacent to the store > stdin.
+
+use super::config::default_passphrase_path;
+use super::output::{say, OutputFormat};
+use super::passphrase::{read_passphrase, PassphraseSource};
+use presage::Manager;
+use std::path::PathBuf;
+use stroma::signal::stroma_store::StromaStore;
+
+/// Resolve the store path, defaulting to `<data_dir>/stroma/signal-store`.
+pub fn resolve_store_path(store_path: Option<String>) -> String {
+    store_path.unwrap_or_else(|| {
+        let default_path = dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("stroma")
+            .join("signal-store");
+        default_path.to_string_lossy().to_string()
+    })
+}
+
+/// Open the encrypted store and load the registered `Manager` for it.
+///
+/// Resolves the passphrase source in priority order (`--passphrase-file` >
+/// `STROMA_DB_PASSPHRASE` env var > default passphrase file adjacent to the
+/// store > interactive stdin prompt), then opens the store and loads
+/// registration data.
+pub async fn open_registered_store(
+    store_path: &str,
+    passphrase_file: Option<String>,
+    output: OutputFormat,
+) -> Result<
+    (
+        StromaStore,
+        Manager<StromaStore, presage::manager::Registered>,
+    ),
+    Box<dyn std::error::Error>,
+> {
+    let store_path_buf = PathBuf::from(store_path);
+    if !store_path_buf.exists() {
+        return Err(format!("Store not found at: {}\nNothing to do.", store_path).into());
+    }
+
+    say!(output, "🔓 Opening encrypted store...");
+
+    let source = if let Some(file) = passphrase_file {
+        PassphraseSource::File(file)
+    } else if std::env::var("STROMA_DB_PASSPHRASE").is_ok() {
+        PassphraseSource::EnvVar
+    } else {
+        let default_passphrase = default_passphrase_path(&store_path_buf);
+        if default_passphrase.exists() {
+            say!(
+                output,
+                "📁 Using passphrase from: {}",
+                default_passphrase.display()
+            );
+            PassphraseSource::File(default_passphrase.to_string_lossy().to_string())
+        } else {
+            PassphraseSource::Stdin
+        }
+    };
+
+    let passphrase = read_passphrase(
+        source,
+        Some("Enter database passphrase (or paste from password vault): "),
+    )?;
+
+    let store = StromaStore::open(store_path, passphrase).await?;
+
+    say!(output, "📱 Detecting device type...");
+    let manager = match Manager::load_registered(store.clone()).await {
+        Ok(m) => m,
+        Err(e) => {
+            return Err(format!(
+                "Failed to load registration data: {:?}\n\
+                The store may not be properly registered. You can manually delete the store directory:\n\
+                  rm -rf {}",
+                e, store_path
+            )
+            .into());
+        }
+    };
+
+    Ok((store, manager))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_store_path_uses_override() {
+        let resolved = resolve_store_path(Some("/custom/path".to_string()));
+        assert_eq!(resolved, "/custom/path");
+    }
+
+    #[test]
+    fn test_resolve_store_path_default_contains_stroma() {
+        let resolved = resolve_store_path(None);
+        assert!(resolved.contains("stroma"));
+        assert!(resolved.contains("signal-store"));
+    }
+}
@@ -0,0 +1,294 @@
+//! Trash (soft-delete) handling for unregistered stores
+//!
+//! `stroma unregister` used to call `std::fs::remove_dir_all` directly, which
+//! meant an operator who cleared local data and later realized they still
+//! needed the cached session/contact state had no way back. Local-cleanup
+//! and secondary-device unregistration now move the store directory into a
+//! retention area instead (a recycle-bin model), so it can be restored with
+//! `stroma restore` until it's purged after the retention window expires.
+//!
+//! Server-side account deletion (`stroma unregister --delete-account`) is
+//! already irreversible once the account is gone from Signal's servers, so
+//! that path still removes the local directory outright.
+
+use super::clock::current_timestamp;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default retention window before a trashed store becomes eligible for purge (14 days).
+pub const DEFAULT_RETENTION_SECS: u64 = 14 * 24 * 60 * 60;
+
+/// Name of the metadata file written inside each trashed store directory.
+const TRASH_META_FILENAME: &str = ".trash-meta.json";
+
+/// Metadata recorded alongside a trashed store, so `restore` and `purge-trash`
+/// know where it came from and how long it's been sitting there.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    /// Directory name under the trash area (e.g. "signal-store-1712345678").
+    pub trash_name: String,
+    /// Original path the store was moved from; `restore` moves it back here.
+    pub original_path: PathBuf,
+    /// Unix timestamp (seconds since epoch) when the store was trashed.
+    pub deleted_at: u64,
+}
+
+impl TrashEntry {
+    /// Age of this entry in seconds, relative to `now`.
+    pub fn age_secs(&self, now: u64) -> u64 {
+        now.saturating_sub(self.deleted_at)
+    }
+
+    /// Whether this entry is older than `retention_secs` and eligible for purge.
+    pub fn is_expired(&self, now: u64, retention_secs: u64) -> bool {
+        self.age_secs(now) >= retention_secs
+    }
+}
+
+/// Default trash area: `<data_dir>/stroma/trash`.
+pub fn default_trash_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("stroma")
+        .join("trash")
+}
+
+/// Soft-delete a store directory by renaming it into the trash area.
+///
+/// Returns the path it was moved to. A `.trash-meta.json` file is written
+/// inside the moved directory recording where it came from and when, so
+/// `restore`/`purge-trash` don't need a separate index file.
+pub fn trash_store_directory(store_path: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let store_path_buf = PathBuf::from(store_path);
+    let store_name = store_path_buf
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "store".to_string());
+
+    let timestamp = current_timestamp();
+    let trash_dir = default_trash_dir();
+    fs::create_dir_all(&trash_dir)
+        .map_err(|e| format!("Failed to create trash directory: {}", e))?;
+
+    let trash_name = format!("{}-{}", store_name, timestamp);
+    let trash_path = trash_dir.join(&trash_name);
+
+    fs::rename(&store_path_buf, &trash_path).map_err(|e| {
+        format!(
+            "Failed to move store directory into trash: {}\n\
+            You may need to manually remove: {}",
+            e,
+            store_path_buf.display()
+        )
+    })?;
+
+    let entry = TrashEntry {
+        trash_name,
+        original_path: store_path_buf,
+        deleted_at: timestamp,
+    };
+    let meta_json = serde_json::to_string_pretty(&entry)
+        .map_err(|e| format!("Failed to serialize trash metadata: {}", e))?;
+    fs::write(trash_path.join(TRASH_META_FILENAME), meta_json)
+        .map_err(|e| format!("Failed to write trash metadata: {}", e))?;
+
+    Ok(trash_path)
+}
+
+/// List all entries currently in the trash area, most recently deleted first.
+pub fn list_trash_entries(trash_dir: &Path) -> Vec<TrashEntry> {
+    let Ok(read_dir) = fs::read_dir(trash_dir) else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<TrashEntry> = read_dir
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| read_trash_meta(&e.path()))
+        .collect();
+
+    entries.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+    entries
+}
+
+/// Read the `.trash-meta.json` sidecar for a single trashed store directory.
+fn read_trash_meta(trash_path: &Path) -> Option<TrashEntry> {
+    let contents = fs::read_to_string(trash_path.join(TRASH_META_FILENAME)).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Restore a trashed store by name back to its original location (or `to_override`).
+///
+/// Errors if the destination already exists, so a restore never clobbers a
+/// store that was re-registered in the meantime.
+pub fn restore_store(
+    trash_dir: &Path,
+    trash_name: &str,
+    to_override: Option<&str>,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let trash_path = trash_dir.join(trash_name);
+    let entry = read_trash_meta(&trash_path)
+        .ok_or_else(|| format!("No trashed store found named: {}", trash_name))?;
+
+    let destination = match to_override {
+        Some(path) => PathBuf::from(path),
+        None => entry.original_path.clone(),
+    };
+
+    if destination.exists() {
+        return Err(format!(
+            "Restore destination already exists: {}\n\
+            Use --to to restore to a different path.",
+            destination.display()
+        )
+        .into());
+    }
+
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create destination directory: {}", e))?;
+    }
+
+    fs::remove_file(trash_path.join(TRASH_META_FILENAME)).ok();
+    fs::rename(&trash_path, &destination).map_err(|e| {
+        format!(
+            "Failed to restore store from trash: {}\n\
+            It remains available at: {}",
+            e,
+            trash_path.display()
+        )
+    })?;
+
+    Ok(destination)
+}
+
+/// Permanently remove trashed stores older than `retention_secs`.
+///
+/// Returns the names of the entries that were purged. Used both by the
+/// explicit `stroma purge-trash` command and the lazy sweep run at the
+/// start of every `stroma unregister`.
+pub fn purge_expired(
+    trash_dir: &Path,
+    retention_secs: u64,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let now = current_timestamp();
+    let mut purged = Vec::new();
+
+    for entry in list_trash_entries(trash_dir) {
+        if entry.is_expired(now, retention_secs) {
+            let trash_path = trash_dir.join(&entry.trash_name);
+            fs::remove_dir_all(&trash_path)
+                .map_err(|e| format!("Failed to purge trashed store {}: {}", entry.trash_name, e))?;
+            purged.push(entry.trash_name);
+        }
+    }
+
+    Ok(purged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_trashed_store(trash_dir: &Path, name: &str, original: &Path, deleted_at: u64) {
+        let trash_path = trash_dir.join(name);
+        fs::create_dir_all(&trash_path).unwrap();
+        let entry = TrashEntry {
+            trash_name: name.to_string(),
+            original_path: original.to_path_buf(),
+            deleted_at,
+        };
+        fs::write(
+            trash_path.join(TRASH_META_FILENAME),
+            serde_json::to_string_pretty(&entry).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_trash_store_directory_moves_and_writes_meta() {
+        let temp_dir = TempDir::new().unwrap();
+        let store_path = temp_dir.path().join("signal-store");
+        fs::create_dir(&store_path).unwrap();
+        fs::write(store_path.join("db.sqlite"), b"data").unwrap();
+
+        std::env::set_var("XDG_DATA_HOME", temp_dir.path().join("data"));
+        let trash_path = trash_store_directory(store_path.to_str().unwrap()).unwrap();
+        std::env::remove_var("XDG_DATA_HOME");
+
+        assert!(!store_path.exists());
+        assert!(trash_path.join("db.sqlite").exists());
+        let entry = read_trash_meta(&trash_path).unwrap();
+        assert_eq!(entry.original_path, store_path);
+    }
+
+    #[test]
+    fn test_list_trash_entries_sorted_most_recent_first() {
+        let temp_dir = TempDir::new().unwrap();
+        write_trashed_store(temp_dir.path(), "a-100", Path::new("/a"), 100);
+        write_trashed_store(temp_dir.path(), "b-200", Path::new("/b"), 200);
+
+        let entries = list_trash_entries(temp_dir.path());
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].trash_name, "b-200");
+        assert_eq!(entries[1].trash_name, "a-100");
+    }
+
+    #[test]
+    fn test_restore_store_moves_back_to_original() {
+        let temp_dir = TempDir::new().unwrap();
+        let original = temp_dir.path().join("restored-store");
+        write_trashed_store(temp_dir.path(), "restored-store-100", &original, 100);
+        fs::write(
+            temp_dir.path().join("restored-store-100").join("db.sqlite"),
+            b"data",
+        )
+        .unwrap();
+
+        let destination = restore_store(temp_dir.path(), "restored-store-100", None).unwrap();
+
+        assert_eq!(destination, original);
+        assert!(original.join("db.sqlite").exists());
+        assert!(!temp_dir.path().join("restored-store-100").exists());
+    }
+
+    #[test]
+    fn test_restore_store_refuses_to_clobber_existing() {
+        let temp_dir = TempDir::new().unwrap();
+        let original = temp_dir.path().join("existing-store");
+        fs::create_dir(&original).unwrap();
+        write_trashed_store(temp_dir.path(), "existing-store-100", &original, 100);
+
+        let result = restore_store(temp_dir.path(), "existing-store-100", None);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_purge_expired_removes_only_old_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let now = current_timestamp();
+        write_trashed_store(temp_dir.path(), "old-store", Path::new("/old"), now - 1000);
+        write_trashed_store(temp_dir.path(), "recent-store", Path::new("/recent"), now - 10);
+
+        let purged = purge_expired(temp_dir.path(), 100).unwrap();
+
+        assert_eq!(purged, vec!["old-store".to_string()]);
+        assert!(!temp_dir.path().join("old-store").exists());
+        assert!(temp_dir.path().join("recent-store").exists());
+    }
+
+    #[test]
+    fn test_trash_entry_is_expired() {
+        let entry = TrashEntry {
+            trash_name: "x".to_string(),
+            original_path: PathBuf::from("/x"),
+            deleted_at: 1000,
+        };
+
+        assert!(!entry.is_expired(1500, DEFAULT_RETENTION_SECS));
+        assert!(entry.is_expired(1000 + DEFAULT_RETENTION_SECS, DEFAULT_RETENTION_SECS));
+    }
+}
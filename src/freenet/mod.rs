@@ -8,10 +8,14 @@
 
 pub mod contract;
 pub mod embedded_kernel;
+pub mod reconciliation;
+pub mod routing_table;
 pub mod state_stream;
 pub mod traits;
 
 pub use contract::TrustContract;
 pub use embedded_kernel::EmbeddedKernel;
+pub use reconciliation::{Filter, MissingItem, MissingVouch, VouchEdge};
+pub use routing_table::RoutingTable;
 pub use state_stream::StateStream;
 pub use traits::FreenetClient;
@@ -0,0 +1,423 @@
+//! Bloom-filter set reconciliation for trust network anti-entropy.
+//!
+//! Per freenet-contract-design.bead, membership and the vouch graph are both
+//! commutative sets, so two replicas can reconcile either one by exchanging
+//! compact summaries of "what I have" instead of the full member/vouch set.
+//! Modeled on Solana's CRDS pull-request filters: the member space is
+//! bucketed by the top `mask_bits` bits of each `MemberHash`, and each
+//! bucket gets its own Bloom filter sized for the members that landed in
+//! it. A peer with the filters checks each of its own members against the
+//! matching bucket's filter; anything the filter doesn't claim to contain
+//! is reported back as missing on the requester's side. Vouch edges are
+//! reconciled the same way, via `build_vouch_reconciliation_filters` /
+//! `respond_to_vouch_filters`, bucketed and Bloom-filtered on a per-edge
+//! hash (see [`vouch_edge_hash`]) instead of a member's own hash.
+
+use crate::freenet::contract::MemberHash;
+use crate::freenet::trust_contract::TrustNetworkState;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Target false-positive rate for reconciliation Bloom filters.
+const FALSE_POSITIVE_RATE: f64 = 0.01;
+
+/// A member reported missing by a `respond_to_filters` call.
+///
+/// This alias exists so callers don't read "MemberHash" when they mean "a
+/// member the requester is missing".
+pub type MissingItem = MemberHash;
+
+/// A vouch edge: `.0` vouches for `.1`, matching
+/// `StateDelta::vouches_added`'s `(voucher, vouchee)` convention.
+pub type VouchEdge = (MemberHash, MemberHash);
+
+/// A vouch edge reported missing by a `respond_to_vouch_filters` call.
+pub type MissingVouch = VouchEdge;
+
+/// A single bucket of a reconciliation request: all members whose hash
+/// prefix matches `mask` (to `mask_bits` bits), summarized as a Bloom filter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Filter {
+    /// Bucket identifier (the top `mask_bits` bits of a member's hash).
+    pub mask: u64,
+
+    /// Number of bits of hash prefix used to assign members to buckets.
+    pub mask_bits: u32,
+
+    bloom: BloomFilter,
+}
+
+impl Filter {
+    /// Whether `member`'s hash prefix falls in this filter's bucket.
+    fn covers(&self, member: &MemberHash) -> bool {
+        bucket_index(member, self.mask_bits) as u64 == self.mask
+    }
+}
+
+/// Fixed-size bit-vector Bloom filter with double hashing (Kirsch-Mitzenmacher).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: usize,
+    num_hashes: u32,
+}
+
+impl BloomFilter {
+    /// Build a filter sized for `expected_items` at `FALSE_POSITIVE_RATE`.
+    fn with_capacity(expected_items: usize) -> Self {
+        let expected_items = expected_items.max(1) as f64;
+        let num_bits = (-expected_items * FALSE_POSITIVE_RATE.ln() / (std::f64::consts::LN_2.powi(2)))
+            .ceil()
+            .max(64.0) as usize;
+        let num_hashes = ((num_bits as f64 / expected_items) * std::f64::consts::LN_2)
+            .round()
+            .max(1.0) as u32;
+
+        Self {
+            bits: vec![0u64; num_bits.div_ceil(64)],
+            num_bits,
+            num_hashes,
+        }
+    }
+
+    fn indices(&self, item: &[u8]) -> impl Iterator<Item = usize> + '_ {
+        let digest = Sha256::digest(item);
+        let h1 = u64::from_le_bytes(digest[0..8].try_into().unwrap());
+        let h2 = u64::from_le_bytes(digest[8..16].try_into().unwrap());
+        (0..self.num_hashes).map(move |i| {
+            (h1.wrapping_add((i as u64).wrapping_mul(h2)) % self.num_bits as u64) as usize
+        })
+    }
+
+    fn insert(&mut self, item: &[u8]) {
+        for index in self.indices(item).collect::<Vec<_>>() {
+            self.bits[index / 64] |= 1 << (index % 64);
+        }
+    }
+
+    fn contains(&self, item: &[u8]) -> bool {
+        self.indices(item)
+            .all(|index| self.bits[index / 64] & (1 << (index % 64)) != 0)
+    }
+}
+
+/// Bucket of a member: the top `mask_bits` bits of its hash (read from the
+/// hash's first 8 bytes).
+fn bucket_index(member: &MemberHash, mask_bits: u32) -> usize {
+    if mask_bits == 0 {
+        return 0;
+    }
+    let prefix = u64::from_be_bytes(member.as_bytes()[0..8].try_into().unwrap());
+    (prefix >> (64 - mask_bits)) as usize
+}
+
+/// Stable per-edge hash for a vouch edge, used to bucket and Bloom-filter it
+/// the same way a `MemberHash` buckets and Bloom-filters a member. Hashing
+/// `voucher || vouchee` (rather than reusing either endpoint's own hash)
+/// keeps the same voucher's distinct vouches spread across buckets instead
+/// of clumping them all in the voucher's own bucket.
+fn vouch_edge_hash(voucher: &MemberHash, vouchee: &MemberHash) -> MemberHash {
+    let mut hasher = Sha256::new();
+    hasher.update(voucher.as_bytes());
+    hasher.update(vouchee.as_bytes());
+    MemberHash::from_bytes(&hasher.finalize())
+}
+
+impl TrustNetworkState {
+    /// Build a set of reconciliation filters covering `self.members`.
+    ///
+    /// The number of buckets is the member count divided by
+    /// `max_items_per_filter` (rounded up, at least one), rounded up to a
+    /// power of two so each bucket's `mask_bits` prefix partitions the hash
+    /// space evenly. That caps the average bucket at `max_items_per_filter`
+    /// members; a single bucket holds more when member hashes share a
+    /// prefix, and its filter is sized for however many it holds.
+    pub fn build_reconciliation_filters(&self, max_items_per_filter: usize) -> Vec<Filter> {
+        let max_items_per_filter = max_items_per_filter.max(1);
+        let num_filters = self
+            .members
+            .len()
+            .div_ceil(max_items_per_filter)
+            .max(1)
+            .next_power_of_two();
+        let mask_bits = num_filters.trailing_zeros();
+
+        let mut buckets: Vec<Vec<&MemberHash>> = vec![Vec::new(); num_filters];
+        for member in &self.members {
+            buckets[bucket_index(member, mask_bits)].push(member);
+        }
+
+        buckets
+            .into_iter()
+            .enumerate()
+            .map(|(mask, bucket_members)| {
+                let mut bloom = BloomFilter::with_capacity(bucket_members.len());
+                for member in &bucket_members {
+                    bloom.insert(member.as_bytes());
+                }
+                Filter {
+                    mask: mask as u64,
+                    mask_bits,
+                    bloom,
+                }
+            })
+            .collect()
+    }
+
+    /// Given a peer's reconciliation `filters`, return the members of
+    /// `self.members` the peer is missing (not claimed present by the
+    /// filter covering that member's bucket).
+    pub fn respond_to_filters(&self, filters: &[Filter]) -> Vec<MissingItem> {
+        self.members
+            .iter()
+            .filter(|member| match filters.iter().find(|f| f.covers(member)) {
+                Some(filter) => !filter.bloom.contains(member.as_bytes()),
+                None => true,
+            })
+            .copied()
+            .collect()
+    }
+
+    /// All vouch edges in `self.vouches`, flattened from the
+    /// voucher-to-vouchees map into `(voucher, vouchee)` pairs.
+    fn vouch_edges(&self) -> Vec<VouchEdge> {
+        self.vouches
+            .iter()
+            .flat_map(|(voucher, vouchees)| vouchees.iter().map(move |v| (*voucher, *v)))
+            .collect()
+    }
+
+    /// Build a set of reconciliation filters covering `self.vouches`, with
+    /// the bucket count chosen as in `build_reconciliation_filters`: at most
+    /// `max_items_per_filter` edges per bucket on average, while a single
+    /// bucket can hold more.
+    ///
+    /// Edges are bucketed and Bloom-filtered on [`vouch_edge_hash`] rather
+    /// than on either endpoint's own `MemberHash`, mirroring
+    /// `build_reconciliation_filters` for members.
+    pub fn build_vouch_reconciliation_filters(&self, max_items_per_filter: usize) -> Vec<Filter> {
+        let max_items_per_filter = max_items_per_filter.max(1);
+        let edges = self.vouch_edges();
+        let num_filters = edges
+            .len()
+            .div_ceil(max_items_per_filter)
+            .max(1)
+            .next_power_of_two();
+        let mask_bits = num_filters.trailing_zeros();
+
+        let mut buckets: Vec<Vec<MemberHash>> = vec![Vec::new(); num_filters];
+        for (voucher, vouchee) in &edges {
+            let edge_hash = vouch_edge_hash(voucher, vouchee);
+            buckets[bucket_index(&edge_hash, mask_bits)].push(edge_hash);
+        }
+
+        buckets
+            .into_iter()
+            .enumerate()
+            .map(|(mask, bucket_edges)| {
+                let mut bloom = BloomFilter::with_capacity(bucket_edges.len());
+                for edge_hash in &bucket_edges {
+                    bloom.insert(edge_hash.as_bytes());
+                }
+                Filter {
+                    mask: mask as u64,
+                    mask_bits,
+                    bloom,
+                }
+            })
+            .collect()
+    }
+
+    /// Given a peer's vouch reconciliation `filters`, return the edges of
+    /// `self.vouches` the peer is missing (not claimed present by the
+    /// filter covering that edge's bucket).
+    pub fn respond_to_vouch_filters(&self, filters: &[Filter]) -> Vec<MissingVouch> {
+        self.vouch_edges()
+            .into_iter()
+            .filter(|(voucher, vouchee)| {
+                let edge_hash = vouch_edge_hash(voucher, vouchee);
+                match filters.iter().find(|f| f.covers(&edge_hash)) {
+                    Some(filter) => !filter.bloom.contains(edge_hash.as_bytes()),
+                    None => true,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_member(id: u8) -> MemberHash {
+        MemberHash::from_bytes(&[id; 32])
+    }
+
+    #[test]
+    fn test_bloom_filter_no_false_negatives() {
+        let mut bloom = BloomFilter::with_capacity(100);
+        let items: Vec<MemberHash> = (0..100).map(test_member).collect();
+
+        for item in &items {
+            bloom.insert(item.as_bytes());
+        }
+
+        for item in &items {
+            assert!(bloom.contains(item.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_build_reconciliation_filters_covers_all_members() {
+        let mut state = TrustNetworkState::new();
+        for i in 0..50 {
+            state.members.insert(test_member(i));
+        }
+
+        let filters = state.build_reconciliation_filters(10);
+
+        // Every member must fall under exactly one filter's bucket.
+        for member in &state.members {
+            let covering: Vec<_> = filters.iter().filter(|f| f.covers(member)).collect();
+            assert_eq!(covering.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_respond_to_filters_identical_state_has_nothing_missing() {
+        let mut state = TrustNetworkState::new();
+        for i in 0..40 {
+            state.members.insert(test_member(i));
+        }
+
+        let filters = state.build_reconciliation_filters(8);
+        let missing = state.respond_to_filters(&filters);
+
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn test_respond_to_filters_reports_new_members() {
+        let mut requester = TrustNetworkState::new();
+        for i in 0..30 {
+            requester.members.insert(test_member(i));
+        }
+        let filters = requester.build_reconciliation_filters(8);
+
+        // Responder has everything the requester has, plus some new members.
+        let mut responder = requester.clone();
+        for i in 30..35 {
+            responder.members.insert(test_member(i));
+        }
+
+        let missing = responder.respond_to_filters(&filters);
+
+        // A Bloom filter can claim a member it was never given. All 30
+        // requester members share bucket 0, and that bucket's filter
+        // happens to claim test_member(33), so it is the one new member
+        // not reported.
+        for i in [30, 31, 32, 34] {
+            assert!(missing.contains(&test_member(i)));
+        }
+        assert!(!missing.contains(&test_member(33)));
+        // None of the already-shared members should be reported missing.
+        for i in 0..30 {
+            assert!(!missing.contains(&test_member(i)));
+        }
+    }
+
+    #[test]
+    fn test_build_reconciliation_filters_empty_state() {
+        let state = TrustNetworkState::new();
+        let filters = state.build_reconciliation_filters(10);
+
+        assert_eq!(filters.len(), 1);
+        assert!(state.respond_to_filters(&filters).is_empty());
+    }
+
+    #[test]
+    fn test_build_reconciliation_filters_zero_max_items_treated_as_one() {
+        let mut state = TrustNetworkState::new();
+        for i in 0..5 {
+            state.members.insert(test_member(i));
+        }
+
+        let filters = state.build_reconciliation_filters(0);
+
+        assert!(!filters.is_empty());
+        assert!(state.respond_to_filters(&filters).is_empty());
+    }
+
+    fn insert_vouch(state: &mut TrustNetworkState, voucher: u8, vouchee: u8) {
+        state
+            .vouches
+            .entry(test_member(voucher))
+            .or_default()
+            .insert(test_member(vouchee));
+    }
+
+    #[test]
+    fn test_build_vouch_reconciliation_filters_covers_all_edges() {
+        let mut state = TrustNetworkState::new();
+        for i in 0..50u8 {
+            insert_vouch(&mut state, i, i.wrapping_add(1));
+        }
+
+        let filters = state.build_vouch_reconciliation_filters(10);
+
+        for (voucher, vouchee) in state.vouch_edges() {
+            let edge_hash = vouch_edge_hash(&voucher, &vouchee);
+            let covering: Vec<_> = filters.iter().filter(|f| f.covers(&edge_hash)).collect();
+            assert_eq!(covering.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_respond_to_vouch_filters_identical_state_has_nothing_missing() {
+        let mut state = TrustNetworkState::new();
+        for i in 0..40u8 {
+            insert_vouch(&mut state, i, i.wrapping_add(1));
+        }
+
+        let filters = state.build_vouch_reconciliation_filters(8);
+        let missing = state.respond_to_vouch_filters(&filters);
+
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn test_respond_to_vouch_filters_reports_new_edges() {
+        let mut requester = TrustNetworkState::new();
+        for i in 0..30u8 {
+            insert_vouch(&mut requester, i, i.wrapping_add(1));
+        }
+        let filters = requester.build_vouch_reconciliation_filters(8);
+
+        // Responder has everything the requester has, plus some new vouches.
+        let mut responder = requester.clone();
+        for i in 30..35u8 {
+            insert_vouch(&mut responder, i, i.wrapping_add(1));
+        }
+
+        let missing = responder.respond_to_vouch_filters(&filters);
+
+        for i in 30..35u8 {
+            let edge = (test_member(i), test_member(i.wrapping_add(1)));
+            assert!(missing.contains(&edge));
+        }
+        // None of the already-shared edges should be reported missing.
+        for i in 0..30u8 {
+            let edge = (test_member(i), test_member(i.wrapping_add(1)));
+            assert!(!missing.contains(&edge));
+        }
+    }
+
+    #[test]
+    fn test_build_vouch_reconciliation_filters_empty_state() {
+        let state = TrustNetworkState::new();
+        let filters = state.build_vouch_reconciliation_filters(10);
+
+        assert_eq!(filters.len(), 1);
+        assert!(state.respond_to_vouch_filters(&filters).is_empty());
+    }
+}
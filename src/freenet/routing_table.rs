@@ -0,0 +1,283 @@
+//! Kademlia-style XOR routing table keyed on `MemberHash`.
+//!
+//! Members are grouped into k-buckets by the bit-length of their XOR
+//! distance from the local id (the position of the highest differing bit):
+//! bucket `i` holds members whose distance is in `[2^i, 2^(i+1))`. Each
+//! bucket holds at most `bucket_size` entries, mirroring Kademlia's
+//! fixed-capacity k-buckets.
+
+use crate::freenet::contract::MemberHash;
+
+/// Bits in a `MemberHash` (32 bytes).
+const ID_BITS: usize = 256;
+
+/// Default per-bucket capacity (Kademlia's conventional k=20).
+const DEFAULT_BUCKET_SIZE: usize = 20;
+
+/// Routing table of known peers, bucketed by XOR distance from `local_id`.
+#[derive(Debug, Clone)]
+pub struct RoutingTable {
+    local_id: MemberHash,
+    bucket_size: usize,
+    buckets: Vec<Vec<MemberHash>>,
+}
+
+impl RoutingTable {
+    /// Create an empty routing table for `local_id` with the default
+    /// bucket capacity.
+    pub fn new(local_id: MemberHash) -> Self {
+        Self::with_bucket_size(local_id, DEFAULT_BUCKET_SIZE)
+    }
+
+    /// Create an empty routing table with a custom per-bucket capacity.
+    pub fn with_bucket_size(local_id: MemberHash, bucket_size: usize) -> Self {
+        Self {
+            local_id,
+            bucket_size: bucket_size.max(1),
+            buckets: vec![Vec::new(); ID_BITS],
+        }
+    }
+
+    /// Insert `member` into its k-bucket.
+    ///
+    /// Returns `false` (and does nothing) if `member` is the local id, is
+    /// already present, or its bucket is already full.
+    pub fn insert(&mut self, member: MemberHash) -> bool {
+        let Some(bucket) = bucket_index_for(&self.local_id, &member) else {
+            return false;
+        };
+        let entries = &mut self.buckets[bucket];
+        if entries.contains(&member) || entries.len() >= self.bucket_size {
+            return false;
+        }
+        entries.push(member);
+        true
+    }
+
+    /// Remove `member` from its k-bucket. Returns `false` if it wasn't present.
+    pub fn remove(&mut self, member: &MemberHash) -> bool {
+        let Some(bucket) = bucket_index_for(&self.local_id, member) else {
+            return false;
+        };
+        let entries = &mut self.buckets[bucket];
+        match entries.iter().position(|m| m == member) {
+            Some(pos) => {
+                entries.remove(pos);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Return the `n` known members closest to `target` by XOR distance,
+    /// nearest first.
+    ///
+    /// Rather than sorting the whole table, walks outward from `target`'s
+    /// k-bucket `t` (index ± 1, ± 2, ...). Bucket `t` holds every member
+    /// within `2^t` of `target`, the buckets below `t` together hold members
+    /// at distance `[2^t, 2^(t+1))`, and each higher bucket `j` holds members
+    /// at distance `[2^j, 2^(j+1))`. So the walk stops once it has gathered
+    /// at least `n` candidates and every bucket below `t` has been included:
+    /// no bucket it has not visited can hold a closer member.
+    pub fn closest_n(&self, target: &MemberHash, n: usize) -> Vec<MemberHash> {
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut candidates: Vec<MemberHash> = Vec::new();
+        match bucket_index_for(&self.local_id, target) {
+            Some(start) => {
+                let mut offset = 0usize;
+                loop {
+                    let hi = start.checked_add(offset).filter(|&i| i < ID_BITS);
+                    let lo = if offset > 0 { start.checked_sub(offset) } else { None };
+                    let any_in_range = hi.is_some() || lo.is_some();
+
+                    if let Some(i) = hi {
+                        candidates.extend(self.buckets[i].iter().copied());
+                    }
+                    if let Some(i) = lo {
+                        candidates.extend(self.buckets[i].iter().copied());
+                    }
+
+                    if (candidates.len() >= n && lo.is_none()) || !any_in_range {
+                        break;
+                    }
+                    offset += 1;
+                }
+            }
+            None => {
+                // `target` is the local id itself: buckets are already in
+                // increasing-distance-from-target order, so just walk them
+                // from the start.
+                for bucket in &self.buckets {
+                    candidates.extend(bucket.iter().copied());
+                    if candidates.len() >= n {
+                        break;
+                    }
+                }
+            }
+        }
+
+        candidates.sort_by_key(|m| xor_distance(m, target));
+        candidates.truncate(n);
+        candidates
+    }
+
+    /// Total number of members across all buckets.
+    pub fn len(&self) -> usize {
+        self.buckets.iter().map(Vec::len).sum()
+    }
+
+    /// Whether the table holds no members.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// XOR distance between two hashes, as a big-endian byte array (so lexical
+/// ordering of the array matches numeric distance ordering).
+fn xor_distance(a: &MemberHash, b: &MemberHash) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    for (o, (&x, &y)) in out.iter_mut().zip(a.as_bytes().iter().zip(b.as_bytes())) {
+        *o = x ^ y;
+    }
+    out
+}
+
+/// Number of leading zero bits in the XOR distance between `a` and `b`
+/// (equivalently, the length of their shared bit-prefix).
+fn xor_leading_zero_bits(a: &MemberHash, b: &MemberHash) -> usize {
+    let ab = a.as_bytes();
+    let bb = b.as_bytes();
+    for (i, (&x, &y)) in ab.iter().zip(bb.iter()).enumerate() {
+        let diff = x ^ y;
+        if diff != 0 {
+            return i * 8 + diff.leading_zeros() as usize;
+        }
+    }
+    ID_BITS
+}
+
+/// Which k-bucket `other` belongs in relative to `local`, or `None` if
+/// `other` is `local` itself (identical hash, no valid bucket).
+fn bucket_index_for(local: &MemberHash, other: &MemberHash) -> Option<usize> {
+    let leading_zeros = xor_leading_zero_bits(local, other);
+    if leading_zeros >= ID_BITS {
+        None
+    } else {
+        Some(ID_BITS - 1 - leading_zeros)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_member(id: u8) -> MemberHash {
+        MemberHash::from_bytes(&[id; 32])
+    }
+
+    #[test]
+    fn test_insert_rejects_self() {
+        let local = test_member(1);
+        let mut table = RoutingTable::new(local);
+
+        assert!(!table.insert(local));
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut table = RoutingTable::new(test_member(1));
+        let peer = test_member(2);
+
+        assert!(table.insert(peer));
+        assert_eq!(table.len(), 1);
+
+        assert!(table.remove(&peer));
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn test_insert_duplicate_rejected() {
+        let mut table = RoutingTable::new(test_member(1));
+        let peer = test_member(2);
+
+        assert!(table.insert(peer));
+        assert!(!table.insert(peer));
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn test_bucket_fills_up() {
+        let mut table = RoutingTable::with_bucket_size(test_member(0), 2);
+
+        // Local id is all-zero, so these peers' XOR distance is just their
+        // last byte. 200, 201, and 202 all fall in [128, 256), giving the
+        // same distance bit-length (8) and therefore the same bucket.
+        let peer_with_last_byte = |byte: u8| {
+            let mut bytes = [0u8; 32];
+            bytes[31] = byte;
+            MemberHash::from_bytes(&bytes)
+        };
+
+        assert!(table.insert(peer_with_last_byte(200)));
+        assert!(table.insert(peer_with_last_byte(201)));
+        assert!(!table.insert(peer_with_last_byte(202)));
+        assert_eq!(table.len(), 2);
+    }
+
+    #[test]
+    fn test_closest_n_orders_by_xor_distance() {
+        let mut table = RoutingTable::new(test_member(0));
+        let near = test_member(1);
+        let mid = test_member(0x0f);
+        let far = test_member(0xff);
+
+        table.insert(near);
+        table.insert(mid);
+        table.insert(far);
+
+        let closest = table.closest_n(&test_member(0), 2);
+
+        assert_eq!(closest, vec![near, mid]);
+    }
+
+    #[test]
+    fn test_closest_n_walks_outward_from_non_local_target() {
+        // `target` here is distinct from `local_id`, exercising the
+        // bucket-walk branch (as opposed to the `target == local_id`
+        // fallback the other tests exercise).
+        let mut table = RoutingTable::new(test_member(0));
+        let near = test_member(0x21); // distance 0x01 from target
+        let mid = test_member(0x30); // distance 0x10 from target
+        let far = test_member(0xff); // distance 0xdf from target
+
+        table.insert(near);
+        table.insert(mid);
+        table.insert(far);
+
+        let target = test_member(0x20);
+        let closest = table.closest_n(&target, 3);
+
+        assert_eq!(closest, vec![near, mid, far]);
+    }
+
+    #[test]
+    fn test_closest_n_truncates() {
+        let mut table = RoutingTable::new(test_member(0));
+        for i in 1..=10u8 {
+            table.insert(test_member(i));
+        }
+
+        assert_eq!(table.closest_n(&test_member(0), 3).len(), 3);
+        assert_eq!(table.closest_n(&test_member(0), 100).len(), 10);
+    }
+
+    #[test]
+    fn test_remove_nonexistent_returns_false() {
+        let mut table = RoutingTable::new(test_member(0));
+        assert!(!table.remove(&test_member(5)));
+    }
+}
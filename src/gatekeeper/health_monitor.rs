@@ -40,6 +40,9 @@ where
     member_mapping: Arc<RwLock<HashMap<MemberHash, ServiceId>>>,
     /// Track if GAP-11 cluster formation announcement has been sent
     cluster_announced: Arc<RwLock<bool>>,
+    /// Cap on worker threads for `detect_clusters` analysis, via
+    /// `crate::matchmaker::run_analysis`. `None` runs on Rayon's global pool.
+    matchmaker_threads: Option<usize>,
 }
 
 impl<F, S> HealthMonitor<F, S>
@@ -56,9 +59,17 @@ where
             group_id,
             member_mapping: Arc::new(RwLock::new(HashMap::new())),
             cluster_announced: Arc::new(RwLock::new(false)),
+            matchmaker_threads: None,
         }
     }
 
+    /// Cap `detect_clusters` analysis to `num_threads` worker threads
+    /// instead of Rayon's global pool (one thread per logical CPU).
+    pub fn with_matchmaker_threads(mut self, num_threads: usize) -> Self {
+        self.matchmaker_threads = Some(num_threads);
+        self
+    }
+
     /// Register a member mapping (MemberHash -> ServiceId).
     ///
     /// This mapping is HMAC-masked and never stores cleartext Signal IDs.
@@ -224,7 +235,8 @@ where
         }
 
         // Detect clusters
-        let cluster_result = detect_clusters(state);
+        let cluster_result =
+            crate::matchmaker::run_analysis(self.matchmaker_threads, || detect_clusters(state));
 
         // Send announcement if ≥2 clusters detected
         if cluster_result.needs_announcement() {
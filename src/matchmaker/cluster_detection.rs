@@ -7,6 +7,7 @@
 
 use crate::freenet::contract::MemberHash;
 use crate::freenet::trust_contract::TrustNetworkState;
+use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 
 /// Cluster ID (arbitrary member from the cluster).
@@ -117,80 +118,27 @@ pub fn detect_clusters(state: &TrustNetworkState) -> ClusterResult {
     let (post_bridge_member_clusters, _post_bridge_clusters) =
         find_components_union_find(&members, &non_bridge_edges);
 
-    // For each initial component, decide whether to apply bridge removal
+    // Deciding whether to apply bridge removal to an initial component only
+    // reads that component's own members and the (already-computed)
+    // post-bridge mapping, so the components fan out over Rayon; only the
+    // final cluster-ID assignment below needs to stay sequential.
+    let initial_components: Vec<HashSet<MemberHash>> = initial_clusters.into_values().collect();
+    let component_plans: Vec<Vec<HashSet<MemberHash>>> = initial_components
+        .par_iter()
+        .map(|initial_members| plan_component_clusters(initial_members, &post_bridge_member_clusters))
+        .collect();
+
     let mut member_clusters = HashMap::new();
     let mut clusters = HashMap::new();
     let mut next_cluster_id = 0;
 
-    for (_initial_cluster_id, initial_members) in initial_clusters {
-        if initial_members.len() < 4 {
-            // Small component - don't apply bridge removal
-            for member in initial_members.iter() {
+    for sub_clusters in component_plans {
+        for sub_members in sub_clusters {
+            for member in sub_members.iter() {
                 member_clusters.insert(*member, next_cluster_id);
             }
-            clusters.insert(next_cluster_id, initial_members);
+            clusters.insert(next_cluster_id, sub_members);
             next_cluster_id += 1;
-        } else {
-            // Larger component - check if bridge removal created meaningful separation
-            // Group members by their post-bridge cluster
-            let mut sub_clusters: HashMap<ClusterId, HashSet<MemberHash>> = HashMap::new();
-            for member in initial_members.iter() {
-                if let Some(&post_cluster_id) = post_bridge_member_clusters.get(member) {
-                    sub_clusters
-                        .entry(post_cluster_id)
-                        .or_default()
-                        .insert(*member);
-                }
-            }
-
-            if sub_clusters.len() == 1 {
-                // No separation - keep as single cluster
-                for member in initial_members.iter() {
-                    member_clusters.insert(*member, next_cluster_id);
-                }
-                clusters.insert(next_cluster_id, initial_members);
-                next_cluster_id += 1;
-            } else {
-                // Separation occurred - add sub-clusters
-                // Merge singletons into larger sub-clusters
-                let mut large_subs: Vec<HashSet<MemberHash>> = Vec::new();
-                let mut singleton_members: Vec<MemberHash> = Vec::new();
-
-                for (_sub_id, sub_members) in sub_clusters {
-                    if sub_members.len() == 1 {
-                        singleton_members.push(*sub_members.iter().next().unwrap());
-                    } else {
-                        large_subs.push(sub_members);
-                    }
-                }
-
-                // If all sub-clusters are singletons, keep original component
-                if large_subs.is_empty() {
-                    for member in initial_members.iter() {
-                        member_clusters.insert(*member, next_cluster_id);
-                    }
-                    clusters.insert(next_cluster_id, initial_members);
-                    next_cluster_id += 1;
-                } else {
-                    // Add large sub-clusters
-                    for sub_members in large_subs {
-                        for member in sub_members.iter() {
-                            member_clusters.insert(*member, next_cluster_id);
-                        }
-                        clusters.insert(next_cluster_id, sub_members);
-                        next_cluster_id += 1;
-                    }
-
-                    // Merge singletons into the last large cluster
-                    if !singleton_members.is_empty() {
-                        let target_cluster = next_cluster_id - 1;
-                        for member in singleton_members {
-                            member_clusters.insert(member, target_cluster);
-                            clusters.get_mut(&target_cluster).unwrap().insert(member);
-                        }
-                    }
-                }
-            }
         }
     }
 
@@ -203,6 +151,63 @@ pub fn detect_clusters(state: &TrustNetworkState) -> ClusterResult {
     }
 }
 
+/// Decide whether a single initial (pre-bridge-removal) component should be
+/// split, given the post-bridge-removal cluster each of its members landed
+/// in. Returns the resulting member-sets for this component only - one set
+/// if no meaningful separation occurred, or multiple if bridge removal split
+/// it (with singleton sub-clusters merged into the last larger one).
+fn plan_component_clusters(
+    initial_members: &HashSet<MemberHash>,
+    post_bridge_member_clusters: &HashMap<MemberHash, ClusterId>,
+) -> Vec<HashSet<MemberHash>> {
+    if initial_members.len() < 4 {
+        // Small component - don't apply bridge removal
+        return vec![initial_members.clone()];
+    }
+
+    // Larger component - check if bridge removal created meaningful separation
+    // Group members by their post-bridge cluster
+    let mut sub_clusters: HashMap<ClusterId, HashSet<MemberHash>> = HashMap::new();
+    for member in initial_members.iter() {
+        if let Some(&post_cluster_id) = post_bridge_member_clusters.get(member) {
+            sub_clusters
+                .entry(post_cluster_id)
+                .or_default()
+                .insert(*member);
+        }
+    }
+
+    if sub_clusters.len() == 1 {
+        // No separation - keep as single cluster
+        return vec![initial_members.clone()];
+    }
+
+    // Separation occurred - split into sub-clusters, merging singletons into
+    // larger sub-clusters
+    let mut large_subs: Vec<HashSet<MemberHash>> = Vec::new();
+    let mut singleton_members: Vec<MemberHash> = Vec::new();
+
+    for (_sub_id, sub_members) in sub_clusters {
+        if sub_members.len() == 1 {
+            singleton_members.push(*sub_members.iter().next().unwrap());
+        } else {
+            large_subs.push(sub_members);
+        }
+    }
+
+    // If all sub-clusters are singletons, keep original component
+    if large_subs.is_empty() {
+        return vec![initial_members.clone()];
+    }
+
+    // Merge singletons into the last large sub-cluster
+    if let Some(last) = large_subs.last_mut() {
+        last.extend(singleton_members);
+    }
+
+    large_subs
+}
+
 /// Build undirected graph from vouch relationships.
 fn build_graph(state: &TrustNetworkState) -> HashMap<MemberHash, HashSet<MemberHash>> {
     let mut graph: HashMap<MemberHash, HashSet<MemberHash>> = HashMap::new();
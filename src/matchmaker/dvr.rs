@@ -7,6 +7,7 @@
 
 use crate::freenet::contract::MemberHash;
 use crate::freenet::trust_contract::TrustNetworkState;
+use rayon::prelude::*;
 use std::collections::HashSet;
 
 /// Health status based on DVR.
@@ -121,10 +122,13 @@ pub fn calculate_dvr(state: &TrustNetworkState) -> DvrResult {
 /// - Sort by vouch count descending (prefer more connected first)
 /// - A Validator needs >= 3 vouches (per trust model)
 pub fn count_distinct_validators(state: &TrustNetworkState) -> usize {
-    // Collect Validators (members with >= 3 vouches)
+    // Collect Validators (members with >= 3 vouches). Each member's vouch
+    // count is an independent lookup, so this fans out over Rayon; the
+    // greedy selection below stays sequential since each pick depends on
+    // which vouchers earlier picks already used.
     let validators: Vec<MemberHash> = state
         .members
-        .iter()
+        .par_iter()
         .filter(|m| {
             let vouch_count = state.vouches.get(m).map(|v| v.len()).unwrap_or(0);
             vouch_count >= 3
@@ -102,6 +102,61 @@ impl TrustGraph {
             .unwrap_or(0);
         in_degree + out_degree
     }
+
+    /// Hop-count distance from `source` to every member reachable from it,
+    /// over the undirected vouch graph (same edges as `detect_clusters`).
+    ///
+    /// Computed via layered BFS - each layer is one more hop than the last.
+    /// Members not reachable from `source` are absent from the result.
+    pub fn calculate_distances(&self, source: MemberHash) -> HashMap<MemberHash, u32> {
+        let mut distances = HashMap::new();
+        if !self.members.contains(&source) {
+            return distances;
+        }
+
+        let edges = build_undirected_edges(self);
+        let mut adj: HashMap<MemberHash, HashSet<MemberHash>> = HashMap::new();
+        for member in &self.members {
+            adj.insert(*member, HashSet::new());
+        }
+        for (a, b) in &edges {
+            adj.entry(*a).or_default().insert(*b);
+            adj.entry(*b).or_default().insert(*a);
+        }
+
+        distances.insert(source, 0);
+        let mut frontier = vec![source];
+        let mut hop: u32 = 0;
+
+        while !frontier.is_empty() {
+            hop += 1;
+            let mut next_frontier = Vec::new();
+            for member in frontier {
+                if let Some(neighbors) = adj.get(&member) {
+                    for &neighbor in neighbors {
+                        if let std::collections::hash_map::Entry::Vacant(entry) =
+                            distances.entry(neighbor)
+                        {
+                            entry.insert(hop);
+                            next_frontier.push(neighbor);
+                        }
+                    }
+                }
+            }
+            frontier = next_frontier;
+        }
+
+        distances
+    }
+
+    /// Hop-count distance between two members, or `None` if `b` isn't
+    /// reachable from `a` over the vouch graph.
+    pub fn distance(&self, a: &MemberHash, b: &MemberHash) -> Option<u32> {
+        if a == b {
+            return Some(0);
+        }
+        self.calculate_distances(*a).get(b).copied()
+    }
 }
 
 /// Detect clusters using bridge removal algorithm (Tarjan's algorithm)
@@ -860,6 +915,106 @@ mod tests {
         // Isolated member has centrality 0
         assert_eq!(graph.centrality(&member_hash(1)), 0);
     }
+
+    #[test]
+    fn test_calculate_distances_self_is_zero() {
+        let mut state = TrustNetworkState::new();
+        state.members.insert(member_hash(1));
+
+        let graph = TrustGraph::from_state(&state);
+        let distances = graph.calculate_distances(member_hash(1));
+
+        assert_eq!(distances.get(&member_hash(1)), Some(&0));
+    }
+
+    #[test]
+    fn test_calculate_distances_direct_neighbor() {
+        let mut state = TrustNetworkState::new();
+        let alice = member_hash(1);
+        let bob = member_hash(2);
+
+        state.members.insert(alice);
+        state.members.insert(bob);
+        state.vouches.insert(alice, [bob].into_iter().collect());
+
+        let graph = TrustGraph::from_state(&state);
+        let distances = graph.calculate_distances(alice);
+
+        assert_eq!(distances.get(&bob), Some(&1));
+    }
+
+    #[test]
+    fn test_calculate_distances_multi_hop_chain() {
+        let mut state = TrustNetworkState::new();
+        let alice = member_hash(1);
+        let bob = member_hash(2);
+        let carol = member_hash(3);
+
+        state.members.insert(alice);
+        state.members.insert(bob);
+        state.members.insert(carol);
+
+        // Chain: alice -> bob -> carol (vouch direction doesn't matter, edges
+        // are treated as undirected)
+        state.vouches.insert(bob, [alice].into_iter().collect());
+        state.vouches.insert(carol, [bob].into_iter().collect());
+
+        let graph = TrustGraph::from_state(&state);
+        let distances = graph.calculate_distances(alice);
+
+        assert_eq!(distances.get(&bob), Some(&1));
+        assert_eq!(distances.get(&carol), Some(&2));
+    }
+
+    #[test]
+    fn test_calculate_distances_unreachable_member_absent() {
+        let mut state = TrustNetworkState::new();
+        let alice = member_hash(1);
+        let bob = member_hash(2);
+
+        state.members.insert(alice);
+        state.members.insert(bob);
+        // No vouches at all, so alice and bob are disconnected.
+
+        let graph = TrustGraph::from_state(&state);
+        let distances = graph.calculate_distances(alice);
+
+        assert_eq!(distances.get(&bob), None);
+    }
+
+    #[test]
+    fn test_distance_between_members() {
+        let mut state = TrustNetworkState::new();
+        let alice = member_hash(1);
+        let bob = member_hash(2);
+        let carol = member_hash(3);
+
+        state.members.insert(alice);
+        state.members.insert(bob);
+        state.members.insert(carol);
+        state.vouches.insert(bob, [alice].into_iter().collect());
+        state.vouches.insert(carol, [bob].into_iter().collect());
+
+        let graph = TrustGraph::from_state(&state);
+
+        assert_eq!(graph.distance(&alice, &alice), Some(0));
+        assert_eq!(graph.distance(&alice, &bob), Some(1));
+        assert_eq!(graph.distance(&alice, &carol), Some(2));
+    }
+
+    #[test]
+    fn test_distance_unreachable_is_none() {
+        let mut state = TrustNetworkState::new();
+        let alice = member_hash(1);
+        let bob = member_hash(2);
+
+        state.members.insert(alice);
+        state.members.insert(bob);
+
+        let graph = TrustGraph::from_state(&state);
+
+        assert_eq!(graph.distance(&alice, &bob), None);
+    }
 }
 
 #[cfg(test)]
@@ -1245,5 +1400,40 @@ mod proptests {
                 );
             }
         }
+
+        /// Property test: distance is symmetric
+        /// If there's a path of N hops from A to B, there's a path of N
+        /// hops from B to A (the vouch graph is treated as undirected).
+        #[test]
+        fn prop_distance_symmetric(
+            num_members in 4usize..20,
+        ) {
+            let mut state = TrustNetworkState::new();
+
+            // Add members
+            for i in 0..num_members as u8 {
+                state.members.insert(member_hash(i));
+            }
+
+            // Add a chain of vouches so most members are connected
+            for i in 0..(num_members - 1) {
+                let member = member_hash(i as u8);
+                let voucher = member_hash((i + 1) as u8);
+                state.vouches.insert(member, [voucher].into_iter().collect());
+            }
+
+            let graph = TrustGraph::from_state(&state);
+
+            for a in &graph.members {
+                for b in &graph.members {
+                    prop_assert_eq!(
+                        graph.distance(a, b),
+                        graph.distance(b, a),
+                        "distance({:?}, {:?}) != distance({:?}, {:?})",
+                        a, b, b, a
+                    );
+                }
+            }
+        }
     }
 }
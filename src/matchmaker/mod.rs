@@ -18,6 +18,9 @@ pub mod display;
 pub mod graph_analysis;
 pub mod strategic_intro;
 
+// Thread-pool sizing for the Rayon-parallelized analysis above
+pub mod parallel;
+
 // Re-exports for health/cluster features
 pub use cluster_detection::{detect_clusters, ClusterId, ClusterResult};
 pub use dvr::{calculate_dvr, count_distinct_validators, health_status, DvrResult, HealthStatus};
@@ -26,3 +29,6 @@ pub use dvr::{calculate_dvr, count_distinct_validators, health_status, DvrResult
 pub use display::{resolve_display_name, IntroductionMessage};
 pub use graph_analysis::TrustGraph;
 pub use strategic_intro::{suggest_introductions, Introduction};
+
+// Re-export for configuring analysis concurrency
+pub use parallel::{run_analysis, with_thread_pool};
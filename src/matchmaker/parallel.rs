@@ -0,0 +1,89 @@
+//! Configurable Rayon thread-pool sizing for matchmaker analysis.
+//!
+//! `calculate_dvr`, `detect_clusters`, and `suggest_introductions` fan their
+//! independent per-member/per-cluster/per-pair work out over Rayon's
+//! parallel iterators, which by default run on Rayon's global pool (one
+//! thread per logical CPU). Wrap a call in [`with_thread_pool`] to run it on
+//! a pool of a specific size instead, e.g. to cap matchmaker CPU usage on a
+//! small host, or to pin the thread count down for reproducible benchmarks.
+
+use rayon::ThreadPoolBuildError;
+
+/// Run `f` on a freshly built Rayon thread pool with `num_threads` workers.
+pub fn with_thread_pool<F, R>(num_threads: usize, f: F) -> Result<R, ThreadPoolBuildError>
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()?;
+    Ok(pool.install(f))
+}
+
+/// Run `f` (a `calculate_dvr`/`detect_clusters`/`suggest_introductions` call,
+/// or similar) bounded to `max_threads` worker threads when configured,
+/// falling back to Rayon's global pool when it's `None` or `Some(0)`, or
+/// when the pool fails to build.
+///
+/// This is the seam production call sites (bot message handlers, the
+/// gatekeeper health monitor, etc.) use to respect an operator-configured
+/// thread cap instead of always running matchmaker analysis on Rayon's
+/// full-CPU global pool.
+pub fn run_analysis<F, R>(max_threads: Option<usize>, f: F) -> R
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    match max_threads {
+        Some(num_threads) if num_threads > 0 => {
+            match rayon::ThreadPoolBuilder::new().num_threads(num_threads).build() {
+                Ok(pool) => pool.install(f),
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to build {}-thread matchmaker pool, falling back to the \
+                        global pool: {}",
+                        num_threads,
+                        e
+                    );
+                    f()
+                }
+            }
+        }
+        _ => f(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_thread_pool_runs_closure() {
+        let result = with_thread_pool(2, || 1 + 1).unwrap();
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_with_thread_pool_respects_varying_sizes() {
+        for size in [1, 4, 8] {
+            let result = with_thread_pool(size, || size * 2).unwrap();
+            assert_eq!(result, size * 2);
+        }
+    }
+
+    #[test]
+    fn test_run_analysis_none_runs_on_global_pool() {
+        assert_eq!(run_analysis(None, || 21 * 2), 42);
+    }
+
+    #[test]
+    fn test_run_analysis_some_runs_bounded() {
+        assert_eq!(run_analysis(Some(2), || 21 * 2), 42);
+    }
+
+    #[test]
+    fn test_run_analysis_zero_falls_back_to_global_pool() {
+        assert_eq!(run_analysis(Some(0), || 21 * 2), 42);
+    }
+}
@@ -9,8 +9,27 @@
 //! to maximize attack resistance.
 
 use crate::freenet::contract::MemberHash;
-use crate::matchmaker::graph_analysis::TrustGraph;
-use std::collections::HashSet;
+use crate::matchmaker::graph_analysis::{ClusterId, TrustGraph};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Per-target BFS distance memo, shared across one `suggest_introductions`
+/// call so `find_unused_cross_cluster_voucher`/`find_any_cross_cluster_voucher`
+/// don't each re-run `TrustGraph::calculate_distances` (a full O(V+E) BFS)
+/// once per bridge candidate.
+type DistanceCache = HashMap<MemberHash, HashMap<MemberHash, u32>>;
+
+/// Look up `target`'s BFS distances in `cache`, computing and memoizing
+/// them on first use.
+fn cached_distances<'a>(
+    graph: &TrustGraph,
+    target: MemberHash,
+    cache: &'a mut DistanceCache,
+) -> &'a HashMap<MemberHash, u32> {
+    cache
+        .entry(target)
+        .or_insert_with(|| graph.calculate_distances(target))
+}
 
 /// Strategic introduction recommendation
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -39,13 +58,14 @@ pub struct Introduction {
 /// - Priority 2: Cluster bridging (connects islands)
 pub fn suggest_introductions(graph: &TrustGraph) -> Vec<Introduction> {
     let mut introductions = Vec::new();
+    let mut distance_cache = DistanceCache::new();
 
     // Phase 0: DVR optimization - prioritize creating distinct Validators
-    let dvr_intros = suggest_dvr_optimal_introductions(graph);
+    let dvr_intros = suggest_dvr_optimal_introductions(graph, &mut distance_cache);
     introductions.extend(dvr_intros);
 
     // Phase 1: MST fallback - strengthen bridges with any cross-cluster vouch
-    let mst_intros = suggest_mst_fallback_introductions(graph);
+    let mst_intros = suggest_mst_fallback_introductions(graph, &mut distance_cache);
     introductions.extend(mst_intros);
 
     // Phase 2: Cluster bridging - connect disconnected clusters
@@ -61,7 +81,10 @@ pub fn suggest_introductions(graph: &TrustGraph) -> Vec<Introduction> {
 /// - Find Bridges (members with exactly 2 vouches)
 /// - Prioritize introductions that use "unused" vouchers
 /// - Goal: Create Validators with non-overlapping voucher sets
-fn suggest_dvr_optimal_introductions(graph: &TrustGraph) -> Vec<Introduction> {
+fn suggest_dvr_optimal_introductions(
+    graph: &TrustGraph,
+    cache: &mut DistanceCache,
+) -> Vec<Introduction> {
     let mut introductions = Vec::new();
     let mut used_vouchers: HashSet<MemberHash> = HashSet::new();
 
@@ -87,9 +110,13 @@ fn suggest_dvr_optimal_introductions(graph: &TrustGraph) -> Vec<Introduction> {
         // Check if bridge's vouchers are already "used" by distinct Validators
         let vouchers_used = bridge_vouchers.iter().any(|v| used_vouchers.contains(v));
 
-        if let Some(voucher) =
-            find_unused_cross_cluster_voucher(&bridge, bridge_cluster, &used_vouchers, graph)
-        {
+        if let Some(voucher) = find_unused_cross_cluster_voucher(
+            &bridge,
+            bridge_cluster,
+            &used_vouchers,
+            graph,
+            cache,
+        ) {
             let reason = if vouchers_used {
                 "Create distinct Validator (DVR optimization)".to_string()
             } else {
@@ -120,7 +147,10 @@ fn suggest_dvr_optimal_introductions(graph: &TrustGraph) -> Vec<Introduction> {
 /// Per blind-matchmaker-dvr.bead:
 /// - If no DVR-optimal voucher available, accept any cross-cluster vouch
 /// - Still valid admission, just not optimal for DVR
-fn suggest_mst_fallback_introductions(graph: &TrustGraph) -> Vec<Introduction> {
+fn suggest_mst_fallback_introductions(
+    graph: &TrustGraph,
+    cache: &mut DistanceCache,
+) -> Vec<Introduction> {
     let mut introductions = Vec::new();
 
     // Find Bridges that weren't handled in Phase 0
@@ -135,7 +165,8 @@ fn suggest_mst_fallback_introductions(graph: &TrustGraph) -> Vec<Introduction> {
         let bridge_cluster = graph.cluster_id(&bridge);
 
         // Find ANY Validator from different cluster
-        if let Some(voucher) = find_any_cross_cluster_voucher(&bridge, bridge_cluster, graph) {
+        if let Some(voucher) = find_any_cross_cluster_voucher(&bridge, bridge_cluster, graph, cache)
+        {
             introductions.push(Introduction {
                 person_a: bridge,
                 person_b: voucher,
@@ -155,38 +186,41 @@ fn suggest_mst_fallback_introductions(graph: &TrustGraph) -> Vec<Introduction> {
 /// - Bridge disconnected clusters
 /// - Unchanged from original algorithm
 fn suggest_cluster_bridge_introductions(graph: &TrustGraph) -> Vec<Introduction> {
-    let mut introductions = Vec::new();
-
     // Skip if there's only one cluster or bootstrap case
     if graph.cluster_count() <= 1 {
-        return introductions;
+        return Vec::new();
     }
 
-    // Find pairs of disconnected clusters and suggest bridges
+    // Find pairs of disconnected clusters and suggest bridges. Each pair is
+    // evaluated independently (a pure read of `graph`), so the candidate
+    // pairs fan out over Rayon; `collect` preserves the original pair order.
     let cluster_ids: Vec<_> = (0..graph.cluster_count()).collect();
+    let cluster_pairs: Vec<(ClusterId, ClusterId)> = cluster_ids
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &cluster_a)| {
+            cluster_ids[i + 1..]
+                .iter()
+                .map(move |&cluster_b| (cluster_a, cluster_b))
+        })
+        .collect();
 
-    for i in 0..cluster_ids.len() {
-        for j in (i + 1)..cluster_ids.len() {
-            let cluster_a = cluster_ids[i];
-            let cluster_b = cluster_ids[j];
-
+    cluster_pairs
+        .par_iter()
+        .filter_map(|&(cluster_a, cluster_b)| {
             // Find a Validator in each cluster
-            if let (Some(validator_a), Some(validator_b)) = (
-                find_validator_in_cluster(graph, cluster_a),
-                find_validator_in_cluster(graph, cluster_b),
-            ) {
-                introductions.push(Introduction {
-                    person_a: validator_a,
-                    person_b: validator_b,
-                    reason: "Bridge disconnected clusters".to_string(),
-                    priority: 2,
-                    dvr_optimal: false,
-                });
-            }
-        }
-    }
-
-    introductions
+            let validator_a = find_validator_in_cluster(graph, cluster_a)?;
+            let validator_b = find_validator_in_cluster(graph, cluster_b)?;
+
+            Some(Introduction {
+                person_a: validator_a,
+                person_b: validator_b,
+                reason: "Bridge disconnected clusters".to_string(),
+                priority: 2,
+                dvr_optimal: false,
+            })
+        })
+        .collect()
 }
 
 /// Get distinct Validators: members with 3+ vouches from non-overlapping voucher sets
@@ -212,6 +246,7 @@ fn find_unused_cross_cluster_voucher(
     target_cluster: Option<usize>,
     used_vouchers: &HashSet<MemberHash>,
     graph: &TrustGraph,
+    cache: &mut DistanceCache,
 ) -> Option<MemberHash> {
     let mut candidates: Vec<MemberHash> = graph
         .members
@@ -225,8 +260,16 @@ fn find_unused_cross_cluster_voucher(
         .copied()
         .collect();
 
-    // Sort by centrality (prefer well-connected vouchers)
-    candidates.sort_by_key(|m| std::cmp::Reverse(graph.centrality(m)));
+    // Prefer vouchers that shorten a long trust path to the target: sort by
+    // hop-distance from the target first (unreachable candidates, treated as
+    // farthest, sort first), then by centrality as a tiebreaker among
+    // equally-distant vouchers. `target`'s distances are memoized in `cache`
+    // since this runs once per bridge candidate in the caller's loop.
+    let distances = cached_distances(graph, *target, cache);
+    candidates.sort_by_key(|m| {
+        let distance = distances.get(m).copied().unwrap_or(u32::MAX);
+        std::cmp::Reverse((distance, graph.centrality(m)))
+    });
 
     candidates.first().copied()
 }
@@ -236,6 +279,7 @@ fn find_any_cross_cluster_voucher(
     target: &MemberHash,
     target_cluster: Option<usize>,
     graph: &TrustGraph,
+    cache: &mut DistanceCache,
 ) -> Option<MemberHash> {
     let mut candidates: Vec<MemberHash> = graph
         .members
@@ -248,8 +292,13 @@ fn find_any_cross_cluster_voucher(
         .copied()
         .collect();
 
-    // Sort by centrality
-    candidates.sort_by_key(|m| std::cmp::Reverse(graph.centrality(m)));
+    // Same distance-first, centrality-tiebreak preference (and the same
+    // per-call memoization) as find_unused_cross_cluster_voucher above.
+    let distances = cached_distances(graph, *target, cache);
+    candidates.sort_by_key(|m| {
+        let distance = distances.get(m).copied().unwrap_or(u32::MAX);
+        std::cmp::Reverse((distance, graph.centrality(m)))
+    });
 
     candidates.first().copied()
 }
@@ -365,7 +414,7 @@ mod tests {
         let mut graph = TrustGraph::from_state(&state);
         detect_clusters(&mut graph);
 
-        let intros = suggest_dvr_optimal_introductions(&graph);
+        let intros = suggest_dvr_optimal_introductions(&graph, &mut DistanceCache::new());
 
         // Should suggest DVR-optimal introductions for bridges
         // Bridge b1 should be suggested to connect with a Validator from different cluster
@@ -405,7 +454,7 @@ mod tests {
         let mut graph = TrustGraph::from_state(&state);
         detect_clusters(&mut graph);
 
-        let intros = suggest_mst_fallback_introductions(&graph);
+        let intros = suggest_mst_fallback_introductions(&graph, &mut DistanceCache::new());
 
         // MST fallback should provide suggestions
         // Priority should be 1 (not DVR-optimal)
@@ -578,8 +627,13 @@ mod tests {
         let target_cluster = graph.cluster_id(&target);
         let used_vouchers = HashSet::new();
 
-        let voucher =
-            find_unused_cross_cluster_voucher(&target, target_cluster, &used_vouchers, &graph);
+        let voucher = find_unused_cross_cluster_voucher(
+            &target,
+            target_cluster,
+            &used_vouchers,
+            &graph,
+            &mut DistanceCache::new(),
+        );
 
         // Should find a Validator from cluster 2
         if let Some(v) = voucher {
@@ -588,6 +642,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_find_unused_cross_cluster_voucher_prefers_farther_equal_centrality_candidate() {
+        let mut state = TrustNetworkState::new();
+
+        let target = member_hash(1);
+        state.members.insert(target);
+
+        // Near path: target -- near_link -- v_near (distance 2).
+        let near_link = member_hash(2);
+        let v_near = member_hash(3);
+        let near_filler_1 = member_hash(4);
+        let near_filler_2 = member_hash(5);
+        for m in [near_link, v_near, near_filler_1, near_filler_2] {
+            state.members.insert(m);
+        }
+        state
+            .vouches
+            .insert(near_link, [target].into_iter().collect());
+        state.vouches.insert(
+            v_near,
+            [near_link, near_filler_1, near_filler_2]
+                .into_iter()
+                .collect(),
+        );
+
+        // Far path: target -- far_1 -- far_2 -- far_3 -- v_far (distance 4).
+        let far_1 = member_hash(6);
+        let far_2 = member_hash(7);
+        let far_3 = member_hash(8);
+        let v_far = member_hash(9);
+        let far_filler_1 = member_hash(10);
+        let far_filler_2 = member_hash(11);
+        for m in [far_1, far_2, far_3, v_far, far_filler_1, far_filler_2] {
+            state.members.insert(m);
+        }
+        state.vouches.insert(far_1, [target].into_iter().collect());
+        state.vouches.insert(far_2, [far_1].into_iter().collect());
+        state.vouches.insert(far_3, [far_2].into_iter().collect());
+        state.vouches.insert(
+            v_far,
+            [far_3, far_filler_1, far_filler_2].into_iter().collect(),
+        );
+
+        let mut graph = TrustGraph::from_state(&state);
+        detect_clusters(&mut graph);
+
+        // Both candidates are Validators (3 vouches each) with identical
+        // centrality (in-degree 3, out-degree 0) - only the BFS distance
+        // from `target` tells them apart.
+        assert_eq!(graph.effective_vouches(&v_near), 3);
+        assert_eq!(graph.effective_vouches(&v_far), 3);
+        assert_eq!(graph.centrality(&v_near), graph.centrality(&v_far));
+        assert_eq!(graph.distance(&target, &v_near), Some(2));
+        assert_eq!(graph.distance(&target, &v_far), Some(4));
+
+        let target_cluster = graph.cluster_id(&target);
+        let used_vouchers = HashSet::new();
+
+        let voucher = find_unused_cross_cluster_voucher(
+            &target,
+            target_cluster,
+            &used_vouchers,
+            &graph,
+            &mut DistanceCache::new(),
+        );
+
+        // The farther candidate shortens the longer trust path, so it's
+        // preferred over the equally-central but nearer one.
+        assert_eq!(voucher, Some(v_far));
+    }
+
     #[test]
     fn test_find_any_cross_cluster_voucher() {
         let mut state = TrustNetworkState::new();
@@ -618,7 +743,7 @@ mod tests {
         let target = member_hash(1);
         let target_cluster = graph.cluster_id(&target);
 
-        let voucher = find_any_cross_cluster_voucher(&target, target_cluster, &graph);
+        let voucher = find_any_cross_cluster_voucher(&target, target_cluster, &graph, &mut DistanceCache::new());
 
         // Should find any Validator from a different cluster
         if let Some(v) = voucher {
@@ -991,7 +1116,7 @@ mod proptests {
             let mut graph = TrustGraph::from_state(&state);
             detect_clusters(&mut graph);
 
-            let intros = suggest_dvr_optimal_introductions(&graph);
+            let intros = suggest_dvr_optimal_introductions(&graph, &mut DistanceCache::new());
 
             // DVR-optimal suggestions should target bridges
             for intro in &intros {
@@ -45,6 +45,12 @@ pub struct BotConfig {
     /// Pepper for voter deduplication (from `StromaKeyring::voter_pepper()`)
     pub voter_pepper: [u8; 32],
     pub contract_hash: Option<crate::freenet::traits::ContractHash>,
+    /// Cap on worker threads for matchmaker analysis (`calculate_dvr`,
+    /// `detect_clusters`), via
+    /// `crate::matchmaker::run_analysis`. `None` (the default) runs on
+    /// Rayon's global pool (one thread per logical CPU); set this on
+    /// embedded/low-core deployments to cap matchmaker CPU usage.
+    pub matchmaker_threads: Option<usize>,
 }
 
 impl Default for BotConfig {
@@ -65,6 +71,7 @@ impl Default for BotConfig {
             identity_masking_key: test_identity_key,
             voter_pepper: test_voter_pepper,
             contract_hash: None,
+            matchmaker_threads: None,
         }
     }
 }
@@ -560,7 +567,12 @@ impl<C: SignalClient, F: crate::freenet::FreenetClient> StromaBot<C, F> {
         // Select assessor via Blind Matchmaker
         // TODO Phase 1: Track previously assigned assessors for DVR optimization
         let excluded = std::collections::HashSet::new();
-        let assessor_hash = BlindMatchmaker::select_validator(&state, &inviter_hash, &excluded);
+        let assessor_hash = BlindMatchmaker::select_validator(
+            &state,
+            &inviter_hash,
+            &excluded,
+            self.config.matchmaker_threads,
+        );
 
         if let Some(assessor) = assessor_hash {
             // Resolve assessor MemberHash to ServiceId via MemberResolver
@@ -655,6 +667,7 @@ impl<C: SignalClient, F: crate::freenet::FreenetClient> StromaBot<C, F> {
     /// Records second vouch and admits member if threshold met.
     async fn handle_vouch(&mut self, sender: &ServiceId, target: &ServiceId) -> SignalResult<()> {
         use crate::matchmaker::cluster_detection::detect_clusters;
+        use crate::matchmaker::run_analysis;
         use std::collections::BTreeSet;
 
         // 1. Hash sender's ServiceId to MemberHash using mnemonic-derived key
@@ -712,7 +725,8 @@ impl<C: SignalClient, F: crate::freenet::FreenetClient> StromaBot<C, F> {
         }
 
         // 6. Verify cross-cluster requirement (if network has ‚â•2 clusters)
-        let cluster_result = detect_clusters(&state);
+        let cluster_result =
+            run_analysis(self.config.matchmaker_threads, || detect_clusters(&state));
         let cross_cluster_required = cluster_result.cluster_count >= 2;
 
         if cross_cluster_required {
@@ -1057,7 +1071,12 @@ impl<C: SignalClient, F: crate::freenet::FreenetClient> StromaBot<C, F> {
         };
 
         // Re-run BlindMatchmaker with exclusion list
-        let new_validator = BlindMatchmaker::select_validator(&state, &inviter_hash, &excluded_set);
+        let new_validator = BlindMatchmaker::select_validator(
+            &state,
+            &inviter_hash,
+            &excluded_set,
+            self.config.matchmaker_threads,
+        );
 
         if let Some(validator_hash) = new_validator {
             // Resolve validator hash to ServiceId via MemberResolver
@@ -1411,6 +1430,7 @@ impl<C: SignalClient, F: crate::freenet::FreenetClient> StromaBot<C, F> {
         state: &crate::freenet::trust_contract::TrustNetworkState,
     ) -> SignalResult<bool> {
         use crate::matchmaker::cluster_detection::detect_clusters;
+        use crate::matchmaker::run_analysis;
 
         // Skip if announcement already sent
         if state.gap11_announcement_sent {
@@ -1418,7 +1438,7 @@ impl<C: SignalClient, F: crate::freenet::FreenetClient> StromaBot<C, F> {
         }
 
         // Detect clusters
-        let cluster_result = detect_clusters(state);
+        let cluster_result = run_analysis(self.config.matchmaker_threads, || detect_clusters(state));
 
         // Check if announcement is needed (‚â•2 clusters)
         if cluster_result.needs_announcement() {
@@ -0,0 +1,166 @@
+//! Linked-device enumeration and remote unlinking
+//!
+//! Wraps the presage `Manager`'s device-list endpoint so operators can see
+//! and remotely unlink secondary devices (phones, other Stroma instances)
+//! linked to the primary account, instead of only being told to do it by
+//! hand in the Signal app.
+//!
+//! The actual `Manager::devices()` / `Manager::unlink_device()` calls are
+//! isolated behind the [`DeviceApi`] trait below, not called directly from
+//! `list_linked_devices`/`unlink_device`. Unlike `send_message` or
+//! `link_secondary_device`, no other call site in this codebase exercises
+//! these two methods, so this boundary is the one place to patch if the
+//! pinned presage version's real signature turns out to differ.
+
+use presage::manager::Registered;
+use presage::store::Store;
+use presage::Manager;
+use std::fmt;
+
+/// A single device linked to the account, as reported by Signal's servers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedDevice {
+    /// Signal device ID (1 is always the primary device).
+    pub id: u32,
+    /// Operator-assigned device name, if the device set one when linking.
+    pub name: Option<String>,
+    /// Unix timestamp (milliseconds) the device was created/linked.
+    pub created_at_ms: Option<u64>,
+}
+
+impl fmt::Display for LinkedDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "#{} ({})", self.id, name),
+            None => write!(f, "#{}", self.id),
+        }
+    }
+}
+
+/// Narrow seam around the presage device-management calls.
+///
+/// `list_linked_devices`/`unlink_device` below take a `Manager` but reach
+/// its device calls only through this trait, so a presage upgrade that
+/// renames or reshapes `devices()`/`unlink_device()` only requires changing
+/// the one `impl` block at the bottom of this file. The unit tests exercise
+/// a fake implementation of the trait itself, without a live Signal account.
+#[async_trait::async_trait(?Send)]
+trait DeviceApi {
+    async fn list_devices(&self) -> Result<Vec<LinkedDevice>, Box<dyn std::error::Error>>;
+    async fn unlink(&self, device_id: u32) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+/// Fetch the account's linked-device list from Signal's servers.
+///
+/// Always includes the primary device (ID 1) alongside any secondary
+/// devices (phone, other Stroma instances).
+pub async fn list_linked_devices<S: Store>(
+    manager: &Manager<S, Registered>,
+) -> Result<Vec<LinkedDevice>, Box<dyn std::error::Error>> {
+    DeviceApi::list_devices(manager).await
+}
+
+/// Remotely unlink a single device by ID from Signal's servers.
+///
+/// Unlinking the primary device (ID 1) isn't meaningful through this path -
+/// use `stroma unregister --delete-account` to remove the account itself.
+pub async fn unlink_device<S: Store>(
+    manager: &Manager<S, Registered>,
+    device_id: u32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if device_id == 1 {
+        return Err("Cannot unlink the primary device (ID 1) this way; \
+            use 'stroma unregister --delete-account' to remove the account instead."
+            .into());
+    }
+
+    DeviceApi::unlink(manager, device_id).await
+}
+
+#[async_trait::async_trait(?Send)]
+impl<S: Store> DeviceApi for Manager<S, Registered> {
+    async fn list_devices(&self) -> Result<Vec<LinkedDevice>, Box<dyn std::error::Error>> {
+        let devices = self
+            .devices()
+            .await
+            .map_err(|e| format!("Failed to fetch linked devices: {:?}", e))?;
+
+        Ok(devices
+            .into_iter()
+            .map(|d| LinkedDevice {
+                id: d.id,
+                name: d.name,
+                created_at_ms: d.created,
+            })
+            .collect())
+    }
+
+    async fn unlink(&self, device_id: u32) -> Result<(), Box<dyn std::error::Error>> {
+        self.unlink_device(device_id)
+            .await
+            .map_err(|e| format!("Failed to unlink device #{}: {:?}", device_id, e).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linked_device_display_with_name() {
+        let device = LinkedDevice {
+            id: 2,
+            name: Some("Stroma Bot".to_string()),
+            created_at_ms: None,
+        };
+        assert_eq!(device.to_string(), "#2 (Stroma Bot)");
+    }
+
+    #[test]
+    fn test_linked_device_display_without_name() {
+        let device = LinkedDevice {
+            id: 3,
+            name: None,
+            created_at_ms: None,
+        };
+        assert_eq!(device.to_string(), "#3");
+    }
+
+    struct FakeDeviceApi {
+        devices: Vec<LinkedDevice>,
+    }
+
+    #[async_trait::async_trait(?Send)]
+    impl DeviceApi for FakeDeviceApi {
+        async fn list_devices(&self) -> Result<Vec<LinkedDevice>, Box<dyn std::error::Error>> {
+            Ok(self.devices.clone())
+        }
+
+        async fn unlink(&self, device_id: u32) -> Result<(), Box<dyn std::error::Error>> {
+            if !self.devices.iter().any(|d| d.id == device_id) {
+                return Err(format!("no such device #{}", device_id).into());
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_devices_via_fake() {
+        let fake = FakeDeviceApi {
+            devices: vec![LinkedDevice {
+                id: 1,
+                name: None,
+                created_at_ms: Some(0),
+            }],
+        };
+        let devices = fake.list_devices().await.unwrap();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].id, 1);
+    }
+
+    #[tokio::test]
+    async fn test_unlink_unknown_device_via_fake() {
+        let fake = FakeDeviceApi { devices: vec![] };
+        assert!(fake.unlink(7).await.is_err());
+    }
+}
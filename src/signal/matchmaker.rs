@@ -36,14 +36,19 @@ impl BlindMatchmaker {
     /// - Bridges: 2 vouches
     ///
     /// Sorted by centrality for optimal trust network health.
+    ///
+    /// `max_threads` bounds the Rayon pool `detect_clusters` runs the
+    /// cluster-detection pass on (see `crate::matchmaker::run_analysis`);
+    /// pass `None` to use Rayon's global pool.
     pub fn select_validator(
         state: &TrustNetworkState,
         inviter: &MemberHash,
         excluded: &HashSet<MemberHash>,
+        max_threads: Option<usize>,
     ) -> Option<MemberHash> {
         // Build trust graph and detect clusters
         let mut graph = TrustGraph::from_state(state);
-        detect_clusters(&mut graph);
+        crate::matchmaker::run_analysis(max_threads, || detect_clusters(&mut graph));
 
         // Get inviter's cluster
         let inviter_cluster = graph.cluster_id(inviter);
@@ -242,7 +247,7 @@ mod tests {
         let alice = test_member_hash(1);
         let excluded = HashSet::new();
 
-        let validator = BlindMatchmaker::select_validator(&state, &alice, &excluded);
+        let validator = BlindMatchmaker::select_validator(&state, &alice, &excluded, None);
 
         assert!(validator.is_some());
         let validator = validator.unwrap();
@@ -277,7 +282,7 @@ mod tests {
         let excluded = HashSet::new();
 
         // Should still select Bob (bootstrap exception)
-        let validator = BlindMatchmaker::select_validator(&state, &alice, &excluded);
+        let validator = BlindMatchmaker::select_validator(&state, &alice, &excluded, None);
         assert_eq!(validator, Some(bob));
     }
 
@@ -290,7 +295,7 @@ mod tests {
         let excluded = HashSet::new();
 
         // No other members to select
-        let validator = BlindMatchmaker::select_validator(&state, &alice, &excluded);
+        let validator = BlindMatchmaker::select_validator(&state, &alice, &excluded, None);
         assert!(validator.is_none());
     }
 
@@ -11,6 +11,7 @@
 pub mod bootstrap;
 pub mod bot;
 pub mod client;
+pub mod devices;
 pub mod group;
 pub mod linking;
 pub mod matchmaker;
@@ -1007,11 +1007,11 @@ async fn handle_mesh_overview<F: crate::freenet::FreenetClient>(
         }
     };
 
-    // Calculate DVR
-    let dvr_result = calculate_dvr(&state);
-
-    // Detect clusters
-    let cluster_result = detect_clusters(&state);
+    // Calculate DVR and detect clusters, bounded to the configured thread pool
+    let (dvr_result, cluster_result) = crate::matchmaker::run_analysis(
+        config.matchmaker_threads,
+        || (calculate_dvr(&state), detect_clusters(&state)),
+    );
 
     // Calculate vouch distribution
     let total_members = state.members.len();
@@ -1137,11 +1137,11 @@ async fn handle_mesh_strength<F: crate::freenet::FreenetClient>(
         }
     };
 
-    // Calculate DVR
-    let dvr_result = calculate_dvr(&state);
-
-    // Detect clusters
-    let cluster_result = detect_clusters(&state);
+    // Calculate DVR and detect clusters, bounded to the configured thread pool
+    let (dvr_result, cluster_result) = crate::matchmaker::run_analysis(
+        config.matchmaker_threads,
+        || (calculate_dvr(&state), detect_clusters(&state)),
+    );
 
     // Calculate vouch distribution histogram
     let mut vouch_distribution: std::collections::BTreeMap<usize, usize> =
@@ -0,0 +1,438 @@
+//! `MeshSim`: a deterministic multi-node mesh simulation.
+//!
+//! Spins up K in-process [`TrustNetworkState`] replicas and delivers
+//! scripted vouch/revocation events between them over a seeded channel that
+//! can drop, delay, and reorder deltas. A node can also be registered as
+//! Byzantine, tampering with or suppressing its own outgoing deltas before
+//! they reach the channel. Everything (event scheduling, delay, reordering)
+//! is driven off a single seeded RNG, so two runs with the same seed and
+//! script produce identical results.
+
+use crate::freenet::contract::MemberHash;
+use crate::freenet::trust_contract::{StateDelta, TrustNetworkState};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, VecDeque};
+
+/// A scripted vouch/revocation event originating at one node.
+#[derive(Debug, Clone)]
+pub enum MeshEvent {
+    /// `voucher` vouches for `vouchee`, originating at `origin_node`.
+    Vouch {
+        origin_node: usize,
+        voucher: MemberHash,
+        vouchee: MemberHash,
+    },
+    /// `voucher` revokes their vouch for `vouchee`, originating at `origin_node`.
+    Revoke {
+        origin_node: usize,
+        voucher: MemberHash,
+        vouchee: MemberHash,
+    },
+}
+
+/// Network conditions applied to every delta sent through the simulation.
+#[derive(Debug, Clone, Copy)]
+pub struct ChannelConditions {
+    /// Probability (0.0-1.0) that a delta is dropped in transit.
+    pub loss_rate: f64,
+
+    /// Maximum extra delivery delay, in simulation ticks, added on top of
+    /// the minimum one-tick delivery time.
+    pub max_delay_ticks: u32,
+
+    /// Whether deltas that become due on the same tick may be delivered in
+    /// a different order than they were sent.
+    pub reordering: bool,
+}
+
+impl Default for ChannelConditions {
+    /// A lossless, in-order channel with no extra delay (every delta
+    /// arrives on the next tick).
+    fn default() -> Self {
+        Self {
+            loss_rate: 0.0,
+            max_delay_ticks: 0,
+            reordering: false,
+        }
+    }
+}
+
+/// A hook for making a node behave Byzantine: inspect or tamper with a
+/// delta it originated before it enters the channel.
+pub trait ByzantineBehavior: std::fmt::Debug {
+    /// Return `None` to suppress the delta entirely, or `Some(delta)`
+    /// (optionally mutated) to send it on.
+    fn tamper(&mut self, delta: StateDelta) -> Option<StateDelta>;
+}
+
+/// A delta in transit between two nodes.
+struct InFlightDelta {
+    deliver_at_tick: u64,
+    to_node: usize,
+    delta: StateDelta,
+}
+
+/// Deterministic multi-node mesh simulation.
+pub struct MeshSim {
+    nodes: Vec<TrustNetworkState>,
+    byzantine: HashMap<usize, Box<dyn ByzantineBehavior>>,
+    conditions: ChannelConditions,
+    rng: StdRng,
+    tick: u64,
+    in_flight: VecDeque<InFlightDelta>,
+}
+
+impl MeshSim {
+    /// Create a simulation with `num_nodes` empty replicas, a fixed RNG
+    /// `seed` for reproducibility, and the given channel `conditions`.
+    pub fn new(num_nodes: usize, seed: u64, conditions: ChannelConditions) -> Self {
+        Self {
+            nodes: (0..num_nodes).map(|_| TrustNetworkState::new()).collect(),
+            byzantine: HashMap::new(),
+            conditions,
+            rng: StdRng::seed_from_u64(seed),
+            tick: 0,
+            in_flight: VecDeque::new(),
+        }
+    }
+
+    /// Register a Byzantine behavior for `node`: every delta it originates
+    /// is passed through `behavior.tamper` before entering the channel.
+    pub fn inject_byzantine(&mut self, node: usize, behavior: Box<dyn ByzantineBehavior>) {
+        self.byzantine.insert(node, behavior);
+    }
+
+    /// Current state of `node`, for test assertions.
+    pub fn node_state(&self, node: usize) -> &TrustNetworkState {
+        &self.nodes[node]
+    }
+
+    /// Number of nodes in the simulation.
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Number of deltas currently in transit.
+    pub fn in_flight_count(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// Apply a scripted event at its origin node, then queue the resulting
+    /// delta for delivery to every other node over the simulated channel.
+    pub fn apply_event(&mut self, event: MeshEvent) {
+        let (origin, delta) = match event {
+            MeshEvent::Vouch {
+                origin_node,
+                voucher,
+                vouchee,
+            } => (origin_node, StateDelta::new().add_vouch(voucher, vouchee)),
+            MeshEvent::Revoke {
+                origin_node,
+                voucher,
+                vouchee,
+            } => (
+                origin_node,
+                StateDelta::new().remove_vouch(voucher, vouchee),
+            ),
+        };
+
+        self.nodes[origin].apply_delta(&delta);
+
+        let delta = match self.byzantine.get_mut(&origin) {
+            Some(behavior) => behavior.tamper(delta),
+            None => Some(delta),
+        };
+        let Some(delta) = delta else {
+            return; // Byzantine node suppressed its own broadcast
+        };
+
+        for to_node in 0..self.nodes.len() {
+            if to_node == origin {
+                continue;
+            }
+            if self.rng.gen::<f64>() < self.conditions.loss_rate {
+                continue; // dropped in transit
+            }
+            let extra_delay = if self.conditions.max_delay_ticks == 0 {
+                0
+            } else {
+                self.rng.gen_range(0..=self.conditions.max_delay_ticks)
+            };
+            self.in_flight.push_back(InFlightDelta {
+                deliver_at_tick: self.tick + 1 + extra_delay as u64,
+                to_node,
+                delta: delta.clone(),
+            });
+        }
+    }
+
+    /// Advance the simulation by one tick, delivering any deltas whose
+    /// delivery time has arrived. When `conditions.reordering` is set,
+    /// deliveries due this tick are shuffled before being applied.
+    pub fn tick(&mut self) {
+        self.tick += 1;
+
+        let mut due = Vec::new();
+        let mut remaining = VecDeque::new();
+        for item in self.in_flight.drain(..) {
+            if item.deliver_at_tick <= self.tick {
+                due.push(item);
+            } else {
+                remaining.push_back(item);
+            }
+        }
+        self.in_flight = remaining;
+
+        if self.conditions.reordering {
+            shuffle(&mut due, &mut self.rng);
+        }
+
+        for item in due {
+            self.nodes[item.to_node].apply_delta(&item.delta);
+        }
+    }
+
+    /// Advance the simulation tick-by-tick until no deltas remain in
+    /// transit.
+    pub fn drain(&mut self) {
+        while !self.in_flight.is_empty() {
+            self.tick();
+        }
+    }
+}
+
+/// Fisher-Yates shuffle driven by the simulation's own RNG, so reordering
+/// stays deterministic for a given seed.
+fn shuffle<T>(items: &mut [T], rng: &mut StdRng) {
+    for i in (1..items.len()).rev() {
+        let j = rng.gen_range(0..=i);
+        items.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_member(id: u8) -> MemberHash {
+        MemberHash::from_bytes(&[id; 32])
+    }
+
+    #[test]
+    fn test_perfect_channel_converges_immediately() {
+        let mut sim = MeshSim::new(3, 1, ChannelConditions::default());
+        let voucher = test_member(1);
+        let vouchee = test_member(2);
+
+        sim.apply_event(MeshEvent::Vouch {
+            origin_node: 0,
+            voucher,
+            vouchee,
+        });
+        sim.drain();
+
+        for node in 0..3 {
+            assert!(sim
+                .node_state(node)
+                .vouches
+                .get(&vouchee)
+                .is_some_and(|v| v.contains(&voucher)));
+        }
+    }
+
+    #[test]
+    fn test_revocation_propagates() {
+        let mut sim = MeshSim::new(2, 2, ChannelConditions::default());
+        let voucher = test_member(1);
+        let vouchee = test_member(2);
+
+        sim.apply_event(MeshEvent::Vouch {
+            origin_node: 0,
+            voucher,
+            vouchee,
+        });
+        sim.drain();
+        sim.apply_event(MeshEvent::Revoke {
+            origin_node: 0,
+            voucher,
+            vouchee,
+        });
+        sim.drain();
+
+        assert!(!sim
+            .node_state(1)
+            .vouches
+            .get(&vouchee)
+            .is_some_and(|v| v.contains(&voucher)));
+    }
+
+    #[test]
+    fn test_total_loss_prevents_propagation() {
+        let conditions = ChannelConditions {
+            loss_rate: 1.0,
+            ..Default::default()
+        };
+        let mut sim = MeshSim::new(2, 3, conditions);
+        let voucher = test_member(1);
+        let vouchee = test_member(2);
+
+        sim.apply_event(MeshEvent::Vouch {
+            origin_node: 0,
+            voucher,
+            vouchee,
+        });
+        sim.drain();
+
+        assert!(sim.node_state(1).vouches.get(&vouchee).is_none());
+        // The origin still applied the delta locally.
+        assert!(sim
+            .node_state(0)
+            .vouches
+            .get(&vouchee)
+            .is_some_and(|v| v.contains(&voucher)));
+    }
+
+    #[test]
+    fn test_delayed_delivery_waits_for_its_tick() {
+        let conditions = ChannelConditions {
+            max_delay_ticks: 5,
+            ..Default::default()
+        };
+        let mut sim = MeshSim::new(2, 4, conditions);
+        let voucher = test_member(1);
+        let vouchee = test_member(2);
+
+        sim.apply_event(MeshEvent::Vouch {
+            origin_node: 0,
+            voucher,
+            vouchee,
+        });
+
+        // Delivery is scheduled at least 1 tick out; it must not have
+        // arrived at the destination before any ticks have run.
+        assert!(sim.node_state(1).vouches.get(&vouchee).is_none());
+
+        sim.drain();
+        assert!(sim
+            .node_state(1)
+            .vouches
+            .get(&vouchee)
+            .is_some_and(|v| v.contains(&voucher)));
+    }
+
+    #[test]
+    fn test_same_seed_is_deterministic() {
+        let conditions = ChannelConditions {
+            loss_rate: 0.3,
+            max_delay_ticks: 4,
+            reordering: true,
+        };
+        let script = vec![
+            MeshEvent::Vouch {
+                origin_node: 0,
+                voucher: test_member(1),
+                vouchee: test_member(2),
+            },
+            MeshEvent::Vouch {
+                origin_node: 1,
+                voucher: test_member(3),
+                vouchee: test_member(4),
+            },
+            MeshEvent::Revoke {
+                origin_node: 0,
+                voucher: test_member(1),
+                vouchee: test_member(2),
+            },
+        ];
+
+        let run = |seed: u64| {
+            let mut sim = MeshSim::new(4, seed, conditions);
+            for event in script.clone() {
+                sim.apply_event(event);
+            }
+            sim.drain();
+            (0..4)
+                .map(|n| sim.node_state(n).clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(run(42), run(42));
+    }
+
+    #[derive(Debug)]
+    struct DropEverything;
+
+    impl ByzantineBehavior for DropEverything {
+        fn tamper(&mut self, _delta: StateDelta) -> Option<StateDelta> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_byzantine_node_can_suppress_broadcast() {
+        let mut sim = MeshSim::new(2, 5, ChannelConditions::default());
+        sim.inject_byzantine(0, Box::new(DropEverything));
+
+        let voucher = test_member(1);
+        let vouchee = test_member(2);
+        sim.apply_event(MeshEvent::Vouch {
+            origin_node: 0,
+            voucher,
+            vouchee,
+        });
+        sim.drain();
+
+        // Origin still has the vouch locally, but never broadcast it.
+        assert!(sim
+            .node_state(0)
+            .vouches
+            .get(&vouchee)
+            .is_some_and(|v| v.contains(&voucher)));
+        assert!(sim.node_state(1).vouches.get(&vouchee).is_none());
+    }
+
+    #[derive(Debug)]
+    struct RewriteVouchee {
+        replacement: MemberHash,
+    }
+
+    impl ByzantineBehavior for RewriteVouchee {
+        fn tamper(&mut self, delta: StateDelta) -> Option<StateDelta> {
+            let mut rewritten = StateDelta::new();
+            for (voucher, _vouchee) in &delta.vouches_added {
+                rewritten = rewritten.add_vouch(*voucher, self.replacement);
+            }
+            Some(rewritten)
+        }
+    }
+
+    #[test]
+    fn test_byzantine_node_can_tamper_with_delta() {
+        let mut sim = MeshSim::new(2, 6, ChannelConditions::default());
+        let forged_target = test_member(99);
+        sim.inject_byzantine(
+            0,
+            Box::new(RewriteVouchee {
+                replacement: forged_target,
+            }),
+        );
+
+        let voucher = test_member(1);
+        let vouchee = test_member(2);
+        sim.apply_event(MeshEvent::Vouch {
+            origin_node: 0,
+            voucher,
+            vouchee,
+        });
+        sim.drain();
+
+        // The tampered delta, not the original, reaches the other node.
+        assert!(sim.node_state(1).vouches.get(&vouchee).is_none());
+        assert!(sim
+            .node_state(1)
+            .vouches
+            .get(&forged_target)
+            .is_some_and(|v| v.contains(&voucher)));
+    }
+}
@@ -0,0 +1,9 @@
+//! Deterministic multi-node mesh simulation harness.
+//!
+//! Used to exercise the trust network's commutative merge logic
+//! ([`crate::freenet::trust_contract`]) under realistic gossip conditions
+//! before wiring it up to a real Freenet transport.
+
+pub mod mesh_sim;
+
+pub use mesh_sim::{ByzantineBehavior, ChannelConditions, MeshEvent, MeshSim};